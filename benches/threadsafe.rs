@@ -0,0 +1,142 @@
+//! Benchmarks for the throughput of the threadsafe variant of
+//! [`OutputSubject`] and [`OutputTracker`] when they are used from several
+//! threads concurrently.
+#![allow(missing_docs, unused_crate_dependencies)]
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion, Throughput};
+use output_tracker::threadsafe::{OutputSubject, OutputTracker};
+use std::hint::black_box;
+use std::thread;
+
+const ITEMS_PER_THREAD: usize = 1_000;
+const NUM_THREADS: [usize; 4] = [1, 2, 4, 8];
+
+fn create_tracker(subject: &OutputSubject<u64>) -> OutputTracker<u64> {
+    subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"))
+}
+
+fn emit_from_threads(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("emit");
+
+    for num_threads in NUM_THREADS {
+        group.throughput(Throughput::Elements(
+            (num_threads * ITEMS_PER_THREAD) as u64,
+        ));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(num_threads),
+            &num_threads,
+            |bencher, &num_threads| {
+                bencher.iter(|| {
+                    let subject = OutputSubject::<u64>::new();
+                    let tracker = create_tracker(&subject);
+
+                    thread::scope(|scope| {
+                        for _ in 0..num_threads {
+                            scope.spawn(|| {
+                                for item in 0..ITEMS_PER_THREAD as u64 {
+                                    subject.emit(black_box(item)).unwrap_or_else(|err| {
+                                        panic!("could not emit item {item}: {err}")
+                                    });
+                                }
+                            });
+                        }
+                    });
+
+                    tracker
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn read_output_from_threads(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("output");
+
+    let subject = OutputSubject::<u64>::new();
+    let tracker = create_tracker(&subject);
+    for item in 0..ITEMS_PER_THREAD as u64 {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item}: {err}"));
+    }
+
+    for num_threads in NUM_THREADS {
+        group.throughput(Throughput::Elements(
+            (num_threads * ITEMS_PER_THREAD) as u64,
+        ));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(num_threads),
+            &num_threads,
+            |bencher, &num_threads| {
+                bencher.iter(|| {
+                    thread::scope(|scope| {
+                        for _ in 0..num_threads {
+                            scope.spawn(|| {
+                                black_box(tracker.output()).unwrap_or_else(|err| {
+                                    panic!("could not read tracker output: {err}")
+                                })
+                            });
+                        }
+                    });
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+fn emit_while_reading_output(criterion: &mut Criterion) {
+    let mut group = criterion.benchmark_group("emit_while_reading_output");
+
+    for num_threads in NUM_THREADS {
+        group.throughput(Throughput::Elements(
+            (num_threads * ITEMS_PER_THREAD) as u64,
+        ));
+        group.bench_with_input(
+            BenchmarkId::from_parameter(num_threads),
+            &num_threads,
+            |bencher, &num_threads| {
+                bencher.iter(|| {
+                    let subject = OutputSubject::<u64>::new();
+                    let tracker = create_tracker(&subject);
+
+                    thread::scope(|scope| {
+                        for _ in 0..num_threads {
+                            scope.spawn(|| {
+                                for item in 0..ITEMS_PER_THREAD as u64 {
+                                    subject.emit(black_box(item)).unwrap_or_else(|err| {
+                                        panic!("could not emit item {item}: {err}")
+                                    });
+                                }
+                            });
+                            scope.spawn(|| {
+                                for _ in 0..10 {
+                                    black_box(tracker.output()).unwrap_or_else(|err| {
+                                        panic!("could not read tracker output: {err}")
+                                    });
+                                }
+                            });
+                        }
+                    });
+
+                    tracker
+                });
+            },
+        );
+    }
+
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    emit_from_threads,
+    read_output_from_threads,
+    emit_while_reading_output
+);
+criterion_main!(benches);
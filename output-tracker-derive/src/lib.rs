@@ -0,0 +1,149 @@
+//! Derive macros for the [`output-tracker`] crate.
+//!
+//! The macros of this crate are re-exported by the `output-tracker` crate when
+//! its crate feature `derive` is enabled. They should be used through the
+//! re-export rather than by depending on this crate directly.
+//!
+//! [`output-tracker`]: https://docs.rs/output-tracker
+
+#![doc(html_root_url = "https://docs.rs/output-tracker-derive/0.1.1")]
+
+mod tracked;
+mod tracked_method;
+
+use proc_macro::TokenStream;
+use syn::{parse_macro_input, DeriveInput, ImplItemFn};
+
+/// Generates the subject accessors and tracking functions of a nullable
+/// adapter.
+///
+/// For each field of the struct that is annotated with `#[track]`, the
+/// following functions are generated:
+///
+/// * `<field>()` returns a reference to the `OutputSubject` held by the field.
+/// * `emit_<name>(data)` emits the given data through the subject.
+/// * `track_<name>()` creates an `OutputTracker` for the subject.
+///
+/// The name defaults to the name of the field without the suffix `_subject`.
+///
+/// The following arguments are supported by the `#[track]` attribute on
+/// fields:
+///
+/// | Argument          | Description                                                                                                         |
+/// |:------------------|:--------------------------------------------------------------------------------------------------------------------|
+/// | `output = <type>` | The type of the tracked data. The emitted data is converted using `From`. Defaults to the type of the emitted data. |
+/// | `name = <ident>`  | The name used in the generated functions `emit_<name>()` and `track_<name>()`.                                      |
+///
+/// By default, the generated functions use the non-threadsafe variant of the
+/// output-tracker. The threadsafe variant is selected by annotating the struct
+/// with `#[track(variant = threadsafe)]`.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::OutputSubject;
+/// use output_tracker::Tracked;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct ApiMessage {
+///     subject: String,
+/// }
+///
+/// #[derive(Tracked)]
+/// struct MessageSender {
+///     #[track(name = messages)]
+///     message_subject: OutputSubject<ApiMessage>,
+///     #[track(output = String)]
+///     login_subject: OutputSubject<&'static str>,
+/// }
+///
+/// let sender = MessageSender {
+///     message_subject: OutputSubject::new(),
+///     login_subject: OutputSubject::new(),
+/// };
+/// let messages = sender.track_messages().unwrap();
+/// let logins = sender.track_login().unwrap();
+///
+/// sender.emit_login("alice").unwrap();
+/// sender.emit_messages(ApiMessage { subject: "hello".into() }).unwrap();
+///
+/// assert_eq!(logins.output().unwrap(), vec!["alice".to_string()]);
+/// assert_eq!(messages.output().unwrap(), vec![ApiMessage { subject: "hello".into() }]);
+/// ```
+#[proc_macro_derive(Tracked, attributes(track))]
+pub fn derive_tracked(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    tracked::expand(&input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+/// Emits the result of a method to an `OutputSubject` of the same struct.
+///
+/// The annotated method must take `&self` or `&mut self` and return a
+/// `Result`. When the method returns `Ok`, a clone of the returned value is
+/// emitted to the subject named by the argument `subject`. When the method
+/// returns `Err`, a clone of the error is emitted to the subject named by the
+/// argument `err_subject`. Both arguments are optional, but at least one of
+/// them must be given.
+///
+/// Instead of the returned value or the error, a clone of an argument of the
+/// method can be emitted by naming the argument with `arg = <name>`.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::OutputSubject;
+/// use output_tracker::tracked;
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// struct ApiMessage {
+///     subject: String,
+/// }
+///
+/// struct MailApi {
+///     message_subject: OutputSubject<ApiMessage>,
+///     failure_subject: OutputSubject<String>,
+/// }
+///
+/// impl MailApi {
+///     #[tracked(subject = message_subject, arg = message)]
+///     #[tracked(err_subject = failure_subject)]
+///     fn send_mail(&self, message: ApiMessage) -> Result<(), String> {
+///         if message.subject.is_empty() {
+///             return Err("missing subject".into());
+///         }
+///         Ok(())
+///     }
+/// }
+///
+/// let mail_api = MailApi {
+///     message_subject: OutputSubject::new(),
+///     failure_subject: OutputSubject::new(),
+/// };
+/// let messages = mail_api.message_subject.create_tracker().unwrap();
+/// let failures = mail_api.failure_subject.create_tracker().unwrap();
+///
+/// _ = mail_api.send_mail(ApiMessage { subject: "hello".into() });
+/// _ = mail_api.send_mail(ApiMessage { subject: String::new() });
+///
+/// assert_eq!(messages.output().unwrap(), vec![ApiMessage { subject: "hello".into() }]);
+/// assert_eq!(failures.output().unwrap(), vec!["missing subject".to_string()]);
+/// ```
+#[proc_macro_attribute]
+pub fn tracked(args: TokenStream, item: TokenStream) -> TokenStream {
+    let mut tracked_args = tracked_method::Args::default();
+    let parser = syn::meta::parser(|meta| tracked_args.parse(&meta));
+    parse_macro_input!(args with parser);
+    let method = parse_macro_input!(item as ImplItemFn);
+    tracked_method::expand(&tracked_args, method)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+// workaround for false positive 'unused extern crate' warnings until
+// Rust issue [#95513](https://github.com/rust-lang/rust/issues/95513) is fixed
+#[cfg(test)]
+mod dummy_extern_uses {
+    use output_tracker as _;
+}
@@ -0,0 +1,204 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{Attribute, Data, DeriveInput, Field, GenericArgument, Ident, PathArguments, Type};
+
+const ATTRIBUTE: &str = "track";
+
+pub fn expand(input: &DeriveInput) -> syn::Result<TokenStream> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(
+            input,
+            "`Tracked` can only be derived for structs",
+        ));
+    };
+    let module = variant_module(&input.attrs)?;
+
+    let mut functions = Vec::new();
+    for field in &data.fields {
+        let mut attrs = field
+            .attrs
+            .iter()
+            .filter(|attr| attr.path().is_ident(ATTRIBUTE));
+        let Some(attr) = attrs.next() else {
+            continue;
+        };
+        if let Some(duplicate) = attrs.next() {
+            return Err(syn::Error::new_spanned(
+                duplicate,
+                "only one `#[track]` attribute is supported per field",
+            ));
+        }
+        functions.push(field_functions(field, attr, &module)?);
+    }
+    if functions.is_empty() {
+        return Err(syn::Error::new_spanned(
+            input,
+            "no field is annotated with `#[track]`",
+        ));
+    }
+
+    let name = &input.ident;
+    let (impl_generics, type_generics, where_clause) = input.generics.split_for_impl();
+    Ok(quote! {
+        impl #impl_generics #name #type_generics #where_clause {
+            #(#functions)*
+        }
+    })
+}
+
+fn variant_module(attrs: &[Attribute]) -> syn::Result<Ident> {
+    let mut module = format_ident!("non_threadsafe");
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident(ATTRIBUTE)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("variant") {
+                let variant: Ident = meta.value()?.parse()?;
+                if variant != "threadsafe" && variant != "non_threadsafe" {
+                    return Err(syn::Error::new_spanned(
+                        variant,
+                        "expected `threadsafe` or `non_threadsafe`",
+                    ));
+                }
+                module = variant;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported argument, expected `variant`"))
+            }
+        })?;
+    }
+    Ok(module)
+}
+
+fn field_functions(field: &Field, attr: &Attribute, module: &Ident) -> syn::Result<TokenStream> {
+    let Some(field_name) = &field.ident else {
+        return Err(syn::Error::new_spanned(
+            field,
+            "`#[track]` is only supported on named fields",
+        ));
+    };
+
+    let mut output = None;
+    let mut name = None;
+    if !matches!(attr.meta, syn::Meta::Path(_)) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("output") {
+                output = Some(meta.value()?.parse::<Type>()?);
+                Ok(())
+            } else if meta.path.is_ident("name") {
+                name = Some(meta.value()?.parse::<Ident>()?);
+                Ok(())
+            } else {
+                Err(meta.error("unsupported argument, expected `output` or `name`"))
+            }
+        })?;
+    }
+
+    let subject_type = &field.ty;
+    let data_type = subject_data_type(subject_type)?;
+    let name = name.unwrap_or_else(|| {
+        let field_name = field_name.to_string();
+        format_ident!(
+            "{}",
+            field_name.strip_suffix("_subject").unwrap_or(&field_name)
+        )
+    });
+    let track_function = format_ident!("track_{}", name);
+    let emit_function = format_ident!("emit_{}", name);
+    let accessor_doc = format!("Returns the subject `{field_name}`.");
+    let emit_doc = format!("Emits the given data through the subject `{field_name}`.");
+
+    let bounds = if module == "threadsafe" {
+        quote!(::core::marker::Send + ::core::marker::Sync + 'static)
+    } else {
+        quote!('static)
+    };
+    let (track_doc, output, create_tracker, where_clause) = output.map_or_else(
+        || {
+            (
+                format!(
+                    "Creates an `OutputTracker` that tracks the data emitted through the \
+                     subject `{field_name}`."
+                ),
+                quote!(#data_type),
+                quote!(self.#field_name.create_tracker()),
+                quote! {
+                    #data_type: ::core::clone::Clone + #bounds,
+                },
+            )
+        },
+        |output| {
+            (
+                format!(
+                    "Creates an `OutputTracker` that tracks the data emitted through the \
+                     subject `{field_name}` converted into the tracked type using `From`."
+                ),
+                quote!(#output),
+                quote! {
+                    self.#field_name.create_mapped_tracker(|data: &#data_type| {
+                        <#output as ::core::convert::From<#data_type>>::from(
+                            ::core::clone::Clone::clone(data),
+                        )
+                    })
+                },
+                quote! {
+                    #data_type: ::core::clone::Clone + #bounds,
+                    #output: ::core::convert::From<#data_type> + #bounds,
+                },
+            )
+        },
+    );
+
+    Ok(quote! {
+        #[doc = #accessor_doc]
+        pub const fn #field_name(&self) -> &#subject_type {
+            &self.#field_name
+        }
+
+        #[doc = #emit_doc]
+        pub fn #emit_function(
+            &self,
+            data: #data_type,
+        ) -> ::core::result::Result<(), ::output_tracker::#module::Error> {
+            self.#field_name.emit(data)
+        }
+
+        #[doc = #track_doc]
+        pub fn #track_function(
+            &self,
+        ) -> ::core::result::Result<
+            ::output_tracker::#module::OutputTracker<#output>,
+            ::output_tracker::#module::Error,
+        >
+        where
+            #where_clause
+        {
+            #create_tracker
+        }
+    })
+}
+
+fn subject_data_type(ty: &Type) -> syn::Result<&Type> {
+    let Type::Path(path) = ty else {
+        return Err(unknown_data_type(ty));
+    };
+    let Some(segment) = path.path.segments.last() else {
+        return Err(unknown_data_type(ty));
+    };
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else {
+        return Err(unknown_data_type(ty));
+    };
+    arguments
+        .args
+        .iter()
+        .find_map(|argument| match argument {
+            GenericArgument::Type(data_type) => Some(data_type),
+            _ => None,
+        })
+        .ok_or_else(|| unknown_data_type(ty))
+}
+
+fn unknown_data_type(ty: &Type) -> syn::Error {
+    syn::Error::new_spanned(
+        ty,
+        "can not determine the type of the emitted data, expected a subject of type `OutputSubject<T>`",
+    )
+}
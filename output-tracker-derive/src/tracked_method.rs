@@ -0,0 +1,107 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::meta::ParseNestedMeta;
+use syn::{parse_quote, FnArg, Ident, ImplItemFn, Pat, ReturnType};
+
+#[derive(Default)]
+pub struct Args {
+    subject: Option<Ident>,
+    err_subject: Option<Ident>,
+    arg: Option<Ident>,
+}
+
+impl Args {
+    pub fn parse(&mut self, meta: &ParseNestedMeta<'_>) -> syn::Result<()> {
+        if meta.path.is_ident("subject") {
+            self.subject = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("err_subject") {
+            self.err_subject = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("arg") {
+            self.arg = Some(meta.value()?.parse()?);
+        } else {
+            return Err(
+                meta.error("unsupported argument, expected `subject`, `err_subject` or `arg`")
+            );
+        }
+        Ok(())
+    }
+}
+
+pub fn expand(args: &Args, mut method: ImplItemFn) -> syn::Result<TokenStream> {
+    if args.subject.is_none() && args.err_subject.is_none() {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            "`#[tracked]` requires a `subject` or an `err_subject`",
+        ));
+    }
+    match method.sig.receiver() {
+        Some(receiver) if receiver.reference.is_some() => {},
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &method.sig,
+                "`#[tracked]` is only supported on methods taking `&self` or `&mut self`",
+            ))
+        },
+    }
+    let ReturnType::Type(_, return_type) = &method.sig.output else {
+        return Err(syn::Error::new_spanned(
+            &method.sig,
+            "`#[tracked]` is only supported on methods returning a `Result`",
+        ));
+    };
+    if let Some(arg) = &args.arg {
+        let has_arg = method.sig.inputs.iter().any(|input| match input {
+            FnArg::Typed(typed) => matches!(&*typed.pat, Pat::Ident(pat) if pat.ident == *arg),
+            FnArg::Receiver(_) => false,
+        });
+        if !has_arg {
+            return Err(syn::Error::new_spanned(
+                arg,
+                "the method has no argument with this name",
+            ));
+        }
+    }
+
+    let block = &method.block;
+    let call = if method.sig.asyncness.is_some() {
+        quote!(async #block.await)
+    } else {
+        quote!((|| #block)())
+    };
+    let capture = args
+        .arg
+        .as_ref()
+        .map(|arg| quote!(let __tracked_argument = ::core::clone::Clone::clone(&#arg);));
+    let data = |value: TokenStream| {
+        args.arg.as_ref().map_or_else(
+            || quote!(::core::clone::Clone::clone(#value)),
+            |_| quote!(::core::clone::Clone::clone(&__tracked_argument)),
+        )
+    };
+    let emit_ok = args.subject.as_ref().map(|subject| {
+        let data = data(quote!(value));
+        quote! {
+            if let ::core::result::Result::Ok(value) = &__tracked_result {
+                _ = self.#subject.emit(#data);
+            }
+        }
+    });
+    let emit_err = args.err_subject.as_ref().map(|subject| {
+        let data = data(quote!(error));
+        quote! {
+            if let ::core::result::Result::Err(error) = &__tracked_result {
+                _ = self.#subject.emit(#data);
+            }
+        }
+    });
+
+    method.block = parse_quote!({
+        #capture
+        #[allow(clippy::redundant_closure_call)]
+        let __tracked_result: #return_type = #call;
+        #emit_ok
+        #emit_err
+        __tracked_result
+    });
+    Ok(quote!(#method))
+}
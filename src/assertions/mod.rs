@@ -0,0 +1,321 @@
+//! Assertions on output-trackers for the [`asserting`] crate.
+//!
+//! The [`AssertOutputTracker`] trait provides assertion methods that can be
+//! called directly on an `OutputTracker` of either variant, without reading
+//! and unwrapping its output first:
+//!
+//! ```
+//! use asserting::prelude::*;
+//! use output_tracker::assertions::AssertOutputTracker;
+//! use output_tracker::non_threadsafe::OutputSubject;
+//!
+//! let subject = OutputSubject::new();
+//! let tracker = subject.create_tracker().unwrap();
+//!
+//! subject.emit("created").unwrap();
+//! subject.emit("renamed").unwrap();
+//! subject.emit("deleted").unwrap();
+//!
+//! assert_that!(&tracker)
+//!     .has_tracked_count(3)
+//!     .has_tracked_in_order(["created", "deleted"])
+//!     .has_tracked_exactly(["created", "renamed", "deleted"]);
+//! ```
+//!
+//! If an assertion fails, the failure message lists the full sequence of
+//! tracked items. Assertions comparing the tracked items with expected items
+//! mark missing items with `-` and unexpected items with `+`.
+
+use crate::diff::{diff, Change};
+use crate::matching::Matcher;
+use asserting::spec::{DoFail, FailingStrategy, Spec};
+use std::fmt::{Debug, Write as _};
+
+/// Reads the data collected by an output-tracker.
+///
+/// This trait is implemented for the `OutputTracker` of both variants and for
+/// references to them. It is the subject of the assertions provided by the
+/// [`AssertOutputTracker`] trait.
+pub trait TrackedOutput<M> {
+    /// Returns the data collected by the tracker so far or a description of
+    /// the error that occurred while reading it.
+    fn tracked_output(&self) -> Result<Vec<M>, String>;
+}
+
+impl<T, M> TrackedOutput<M> for &T
+where
+    T: TrackedOutput<M> + ?Sized,
+{
+    fn tracked_output(&self) -> Result<Vec<M>, String> {
+        (**self).tracked_output()
+    }
+}
+
+#[cfg(any(feature = "non-threadsafe", not(feature = "threadsafe")))]
+impl<M> TrackedOutput<M> for crate::non_threadsafe::OutputTracker<M>
+where
+    M: Clone,
+{
+    fn tracked_output(&self) -> Result<Vec<M>, String> {
+        self.output().map_err(|err| err.to_string())
+    }
+}
+
+#[cfg(feature = "_threadsafe-variant")]
+impl<M> TrackedOutput<M> for crate::threadsafe::OutputTracker<M>
+where
+    M: Clone,
+{
+    fn tracked_output(&self) -> Result<Vec<M>, String> {
+        self.output().map_err(|err| err.to_string())
+    }
+}
+
+/// Assertions on the data collected by an output-tracker.
+///
+/// The assertions are available for any subject implementing
+/// [`TrackedOutput`], which includes the `OutputTracker` of both variants.
+#[allow(clippy::return_self_not_must_use)]
+pub trait AssertOutputTracker<M> {
+    /// Verifies that the tracker has tracked exactly the expected items in
+    /// the given order and nothing else.
+    #[track_caller]
+    fn has_tracked_exactly(self, expected: impl IntoIterator<Item = M>) -> Self;
+
+    /// Verifies that the tracker has tracked the expected items in the given
+    /// order.
+    ///
+    /// Other items may have been tracked before, after or between the
+    /// expected items.
+    #[track_caller]
+    fn has_tracked_in_order(self, expected: impl IntoIterator<Item = M>) -> Self;
+
+    /// Verifies that the tracker has not tracked any item.
+    #[track_caller]
+    fn has_tracked_nothing(self) -> Self;
+
+    /// Verifies that the tracker has tracked the expected number of items.
+    #[track_caller]
+    fn has_tracked_count(self, expected: usize) -> Self;
+
+    /// Verifies that the tracker has tracked at least one item matching the
+    /// given predicate.
+    #[track_caller]
+    fn has_tracked_any_matching<P>(self, predicate: P) -> Self
+    where
+        P: Fn(&M) -> bool;
+
+    /// Verifies that the tracked items match the given [`Matcher`], like a
+    /// sequence pattern created by the macro [`seq!`](crate::seq).
+    #[track_caller]
+    fn has_tracked_matching(self, matcher: impl Matcher<M>) -> Self;
+}
+
+impl<S, M, R> AssertOutputTracker<M> for Spec<'_, S, R>
+where
+    S: TrackedOutput<M>,
+    M: Debug + PartialEq,
+    R: FailingStrategy,
+{
+    fn has_tracked_exactly(mut self, expected: impl IntoIterator<Item = M>) -> Self {
+        let expected = expected.into_iter().collect::<Vec<_>>();
+        if let Some(actual) = read_output(&mut self) {
+            if actual != expected {
+                let expression = self.expression();
+                let message = format!(
+                    "expected {expression} to have tracked exactly {} items\n   but was: {} \
+                     items\n  expected: {expected:?}\n\n{}",
+                    expected.len(),
+                    actual.len(),
+                    render_changes(&diff(&expected, &actual), true),
+                );
+                self.do_fail_with_message(message);
+            }
+        }
+        self
+    }
+
+    fn has_tracked_in_order(mut self, expected: impl IntoIterator<Item = M>) -> Self {
+        let expected = expected.into_iter().collect::<Vec<_>>();
+        if let Some(actual) = read_output(&mut self) {
+            let changes = match_in_order(&expected, &actual);
+            let missing = changes
+                .iter()
+                .filter_map(|change| match change {
+                    Change::Removed(item) => Some(*item),
+                    Change::Same(_) | Change::Added(_) => None,
+                })
+                .collect::<Vec<_>>();
+            if !missing.is_empty() {
+                let expression = self.expression();
+                let message = format!(
+                    "expected {expression} to have tracked {expected:?} in order\n   but \
+                     missing: {missing:?}\n\n{}",
+                    render_changes(&changes, false),
+                );
+                self.do_fail_with_message(message);
+            }
+        }
+        self
+    }
+
+    fn has_tracked_nothing(mut self) -> Self {
+        if let Some(actual) = read_output(&mut self) {
+            if !actual.is_empty() {
+                let expression = self.expression();
+                let message = format!(
+                    "expected {expression} to have tracked nothing\n   but was: {} items\n\n{}",
+                    actual.len(),
+                    render_sequence(&actual),
+                );
+                self.do_fail_with_message(message);
+            }
+        }
+        self
+    }
+
+    fn has_tracked_count(mut self, expected: usize) -> Self {
+        if let Some(actual) = read_output(&mut self) {
+            if actual.len() != expected {
+                let expression = self.expression();
+                let message = format!(
+                    "expected {expression} to have tracked {expected} items\n   but was: {} \
+                     items\n\n{}",
+                    actual.len(),
+                    render_sequence(&actual),
+                );
+                self.do_fail_with_message(message);
+            }
+        }
+        self
+    }
+
+    fn has_tracked_any_matching<P>(mut self, predicate: P) -> Self
+    where
+        P: Fn(&M) -> bool,
+    {
+        if let Some(actual) = read_output(&mut self) {
+            if !actual.iter().any(predicate) {
+                let expression = self.expression();
+                let message = format!(
+                    "expected {expression} to have tracked any item matching the predicate\n   \
+                     but none of {} items matched\n\n{}",
+                    actual.len(),
+                    render_sequence(&actual),
+                );
+                self.do_fail_with_message(message);
+            }
+        }
+        self
+    }
+
+    fn has_tracked_matching(mut self, matcher: impl Matcher<M>) -> Self {
+        if let Some(actual) = read_output(&mut self) {
+            if let Err(mismatch) = matcher.verify(&actual) {
+                let expression = self.expression();
+                let message = format!(
+                    "expected {expression} to have tracked items matching the pattern\n   but \
+                     {mismatch}\n\n{}",
+                    render_sequence(&actual),
+                );
+                self.do_fail_with_message(message);
+            }
+        }
+        self
+    }
+}
+
+/// Reads the output of the tracker under test or fails the assertion if the
+/// output can not be read.
+fn read_output<S, M, R>(spec: &mut Spec<'_, S, R>) -> Option<Vec<M>>
+where
+    S: TrackedOutput<M>,
+    R: FailingStrategy,
+{
+    match spec.subject().tracked_output() {
+        Ok(output) => Some(output),
+        Err(err) => {
+            let expression = spec.expression();
+            let message = format!("could not read the output of {expression}, reason: {err}");
+            spec.do_fail_with_message(message);
+            None
+        },
+    }
+}
+
+/// Matches the expected items against the tracked items in order, skipping
+/// tracked items that are not expected next.
+///
+/// Expected items that could not be matched are returned as removed at the
+/// end of the sequence.
+fn match_in_order<'a, M>(expected: &'a [M], actual: &'a [M]) -> Vec<Change<&'a M>>
+where
+    M: PartialEq,
+{
+    let mut expected = expected.iter().peekable();
+    let mut changes = actual
+        .iter()
+        .map(|item| {
+            if expected.next_if(|next| *next == item).is_some() {
+                Change::Same(item)
+            } else {
+                Change::Added(item)
+            }
+        })
+        .collect::<Vec<_>>();
+    changes.extend(expected.map(Change::Removed));
+    changes
+}
+
+/// Renders the tracked items with their index, one item per line.
+fn render_sequence<M>(tracked: &[M]) -> String
+where
+    M: Debug,
+{
+    let mut rendered = String::from("tracked sequence:\n");
+    if tracked.is_empty() {
+        rendered.push_str("    <nothing>\n");
+    }
+    for (index, item) in tracked.iter().enumerate() {
+        _ = writeln!(rendered, "    [{index}] {item:?}");
+    }
+    rendered.truncate(rendered.trim_end().len());
+    rendered
+}
+
+/// Renders the tracked items with their index along with the expected items
+/// missing from the sequence.
+///
+/// Tracked items that are not expected are marked as unexpected only if
+/// `mark_unexpected` is true.
+fn render_changes<M>(changes: &[Change<&M>], mark_unexpected: bool) -> String
+where
+    M: Debug,
+{
+    let mut rendered = if mark_unexpected {
+        String::from("tracked sequence (- missing, + unexpected):\n")
+    } else {
+        String::from("tracked sequence (- missing):\n")
+    };
+    if changes.is_empty() {
+        rendered.push_str("    <nothing>\n");
+    }
+    let mut index = 0;
+    for change in changes {
+        _ = match change {
+            Change::Removed(item) => writeln!(rendered, "  -     {item:?}"),
+            Change::Added(item) if mark_unexpected => writeln!(rendered, "  + [{index}] {item:?}"),
+            Change::Same(item) | Change::Added(item) => {
+                writeln!(rendered, "    [{index}] {item:?}")
+            },
+        };
+        if !matches!(change, Change::Removed(_)) {
+            index += 1;
+        }
+    }
+    rendered.truncate(rendered.trim_end().len());
+    rendered
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,218 @@
+use super::*;
+use asserting::prelude::*;
+
+struct UnreadableTracker;
+
+impl TrackedOutput<i32> for UnreadableTracker {
+    fn tracked_output(&self) -> Result<Vec<i32>, String> {
+        Err("tracker is poisoned".into())
+    }
+}
+
+#[test]
+fn assertions_fail_if_the_output_of_the_tracker_can_not_be_read() {
+    let failures = verify_that!(UnreadableTracker)
+        .has_tracked_count(0)
+        .display_failures();
+
+    assert_that!(failures).contains_exactly([
+        "could not read the output of UnreadableTracker, reason: tracker is poisoned\n",
+    ]);
+}
+
+#[cfg(any(feature = "non-threadsafe", not(feature = "threadsafe")))]
+mod non_threadsafe {
+    use super::*;
+    use crate::matching::{any_number_of, is};
+    use crate::non_threadsafe::{OutputSubject, OutputTracker};
+    use crate::seq;
+
+    fn tracker_with_output(
+        output: impl IntoIterator<Item = &'static str>,
+    ) -> (OutputSubject<&'static str>, OutputTracker<&'static str>) {
+        let subject = OutputSubject::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+        for item in output {
+            subject
+                .emit(item)
+                .unwrap_or_else(|err| panic!("could not emit item {item:?}: {err}"));
+        }
+        (subject, tracker)
+    }
+
+    #[test]
+    fn assertions_on_a_tracker_that_are_fulfilled_do_not_fail() {
+        let (_subject, tracker) = tracker_with_output(["created", "renamed", "deleted"]);
+
+        assert_that!(&tracker)
+            .has_tracked_exactly(["created", "renamed", "deleted"])
+            .has_tracked_in_order(["created", "deleted"])
+            .has_tracked_count(3)
+            .has_tracked_any_matching(|item| item.starts_with("ren"));
+    }
+
+    #[test]
+    fn a_tracker_without_output_has_tracked_nothing() {
+        let (_subject, tracker) = tracker_with_output([]);
+
+        assert_that!(tracker)
+            .has_tracked_nothing()
+            .has_tracked_count(0);
+    }
+
+    #[test]
+    fn has_tracked_exactly_fails_with_a_diff_of_the_tracked_sequence() {
+        let (_subject, tracker) = tracker_with_output(["created", "renamed", "deleted"]);
+
+        let failures = verify_that!(&tracker)
+            .has_tracked_exactly(["created", "deleted", "archived"])
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "expected &tracker to have tracked exactly 3 items\n   \
+             but was: 3 items\n  \
+             expected: [\"created\", \"deleted\", \"archived\"]\n\
+             \n\
+             tracked sequence (- missing, + unexpected):\n    \
+             [0] \"created\"\n  \
+             + [1] \"renamed\"\n    \
+             [2] \"deleted\"\n  \
+             -     \"archived\"\n",
+        ]);
+    }
+
+    #[test]
+    fn has_tracked_in_order_fails_listing_the_missing_items() {
+        let (_subject, tracker) = tracker_with_output(["created", "renamed", "deleted"]);
+
+        let failures = verify_that!(&tracker)
+            .has_tracked_in_order(["deleted", "renamed"])
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "expected &tracker to have tracked [\"deleted\", \"renamed\"] in order\n   \
+             but missing: [\"renamed\"]\n\
+             \n\
+             tracked sequence (- missing):\n    \
+             [0] \"created\"\n    \
+             [1] \"renamed\"\n    \
+             [2] \"deleted\"\n  \
+             -     \"renamed\"\n",
+        ]);
+    }
+
+    #[test]
+    fn has_tracked_nothing_fails_listing_the_tracked_sequence() {
+        let (_subject, tracker) = tracker_with_output(["created", "deleted"]);
+
+        let failures = verify_that!(&tracker)
+            .has_tracked_nothing()
+            .display_failures();
+
+        assert_that!(failures).contains_exactly(["expected &tracker to have tracked nothing\n   \
+             but was: 2 items\n\
+             \n\
+             tracked sequence:\n    \
+             [0] \"created\"\n    \
+             [1] \"deleted\"\n"]);
+    }
+
+    #[test]
+    fn has_tracked_count_fails_listing_the_tracked_sequence() {
+        let (_subject, tracker) = tracker_with_output(["created"]);
+
+        let failures = verify_that!(&tracker)
+            .has_tracked_count(2)
+            .display_failures();
+
+        assert_that!(failures).contains_exactly(["expected &tracker to have tracked 2 items\n   \
+             but was: 1 items\n\
+             \n\
+             tracked sequence:\n    \
+             [0] \"created\"\n"]);
+    }
+
+    #[test]
+    fn has_tracked_any_matching_fails_listing_the_tracked_sequence() {
+        let (_subject, tracker) = tracker_with_output([]);
+
+        let failures = verify_that!(&tracker)
+            .has_tracked_any_matching(|item| item.is_empty())
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "expected &tracker to have tracked any item matching the predicate\n   \
+             but none of 0 items matched\n\
+             \n\
+             tracked sequence:\n    \
+             <nothing>\n",
+        ]);
+    }
+
+    #[test]
+    fn has_tracked_matching_fails_with_the_mismatch_and_the_tracked_sequence() {
+        let (_subject, tracker) = tracker_with_output(["open", "write", "open"]);
+
+        let failures = verify_that!(&tracker)
+            .has_tracked_matching(seq![is("open"), any_number_of(is("write")), is("close")])
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "expected &tracker to have tracked items matching the pattern\n   \
+             but output does not match at index 2: expected any number of \"write\" or \
+             \"close\", but found \"open\"\n\
+             \n\
+             tracked sequence:\n    \
+             [0] \"open\"\n    \
+             [1] \"write\"\n    \
+             [2] \"open\"\n",
+        ]);
+    }
+}
+
+#[cfg(feature = "_threadsafe-variant")]
+mod threadsafe {
+    use super::*;
+    use crate::threadsafe::OutputSubject;
+
+    #[test]
+    fn assertions_can_be_called_on_a_threadsafe_tracker() {
+        let subject = OutputSubject::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        subject
+            .emit(42)
+            .unwrap_or_else(|err| panic!("could not emit item: {err}"));
+
+        assert_that!(&tracker)
+            .has_tracked_exactly([42])
+            .has_tracked_in_order([42])
+            .has_tracked_count(1)
+            .has_tracked_any_matching(|item| *item > 40);
+    }
+
+    #[test]
+    fn has_tracked_exactly_fails_for_a_threadsafe_tracker_that_tracked_nothing() {
+        let subject = OutputSubject::<i32>::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        let failures = verify_that!(tracker)
+            .has_tracked_exactly([1])
+            .display_failures();
+
+        assert_that!(failures).contains_exactly([
+            "expected tracker to have tracked exactly 1 items\n   \
+             but was: 0 items\n  \
+             expected: [1]\n\
+             \n\
+             tracked sequence (- missing, + unexpected):\n  \
+             -     1\n",
+        ]);
+    }
+}
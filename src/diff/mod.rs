@@ -0,0 +1,148 @@
+/// A change between an expected and an actual sequence of items.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Change<T> {
+    /// The item is contained in both sequences.
+    Same(T),
+    /// The item is contained only in the expected sequence.
+    Removed(T),
+    /// The item is contained only in the actual sequence.
+    Added(T),
+}
+
+/// The maximum number of removed and added items for which the minimal
+/// changes between two sequences are computed.
+///
+/// The effort of computing the minimal changes grows with the number of
+/// differences. Sequences that differ in more items are reported as removed
+/// and added as a whole, apart from their common prefix and suffix.
+pub const MAX_EDIT_DISTANCE: usize = 500;
+
+/// Computes the minimal changes between two sequences of items.
+///
+/// The changes are computed with the algorithm of Eugene W. Myers in time
+/// proportional to the length of the sequences times the number of
+/// differences. If the sequences differ in more than [`MAX_EDIT_DISTANCE`]
+/// items, the differing part is listed as removed items followed by added
+/// items instead.
+pub fn diff<'a, T>(expected: &'a [T], actual: &'a [T]) -> Vec<Change<&'a T>>
+where
+    T: PartialEq,
+{
+    let prefix = common_prefix(expected.iter(), actual.iter());
+    let suffix = common_prefix(
+        expected[prefix..].iter().rev(),
+        actual[prefix..].iter().rev(),
+    );
+    let expected_middle = &expected[prefix..expected.len() - suffix];
+    let actual_middle = &actual[prefix..actual.len() - suffix];
+
+    let mut changes = Vec::with_capacity(expected.len().max(actual.len()));
+    changes.extend(expected[..prefix].iter().map(Change::Same));
+    if let Some(middle) = shortest_edit(expected_middle, actual_middle) {
+        changes.extend(middle);
+    } else {
+        changes.extend(expected_middle.iter().map(Change::Removed));
+        changes.extend(actual_middle.iter().map(Change::Added));
+    }
+    changes.extend(expected[expected.len() - suffix..].iter().map(Change::Same));
+    changes
+}
+
+/// Finds the shortest sequence of changes transforming `expected` into
+/// `actual`, or returns `None` if it has more than [`MAX_EDIT_DISTANCE`]
+/// removed and added items.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn shortest_edit<'a, T>(expected: &'a [T], actual: &'a [T]) -> Option<Vec<Change<&'a T>>>
+where
+    T: PartialEq,
+{
+    let (n, m) = (expected.len() as isize, actual.len() as isize);
+    let max_edits = (n + m).min(MAX_EDIT_DISTANCE as isize);
+    // furthest reaching x on each diagonal k = x - y, indexed by k + offset
+    let offset = max_edits + 1;
+    let mut furthest = vec![0_isize; 2 * offset as usize + 1];
+    // the furthest reaching x of the diagonals -d..=d before each round d
+    let mut trace = Vec::new();
+
+    for d in 0..=max_edits {
+        trace.push(furthest[(offset - d) as usize..=(offset + d) as usize].to_vec());
+        for k in (-d..=d).step_by(2) {
+            let index = (k + offset) as usize;
+            let mut x = if k == -d || (k != d && furthest[index - 1] < furthest[index + 1]) {
+                furthest[index + 1]
+            } else {
+                furthest[index - 1] + 1
+            };
+            let mut y = x - k;
+            let snake = common_prefix(
+                expected.get(x as usize..).unwrap_or_default().iter(),
+                actual.get(y as usize..).unwrap_or_default().iter(),
+            ) as isize;
+            x += snake;
+            y += snake;
+            furthest[index] = x;
+            if x >= n && y >= m {
+                return Some(backtrack(expected, actual, &trace));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the number of leading items both sequences have in common.
+fn common_prefix<'a, T>(
+    expected: impl Iterator<Item = &'a T>,
+    actual: impl Iterator<Item = &'a T>,
+) -> usize
+where
+    T: PartialEq + 'a,
+{
+    expected
+        .zip(actual)
+        .take_while(|(expected, actual)| expected == actual)
+        .count()
+}
+
+/// Follows the furthest reaching paths recorded in `trace` back from the end
+/// of both sequences to their start.
+#[allow(clippy::cast_possible_wrap, clippy::cast_sign_loss)]
+fn backtrack<'a, T>(
+    expected: &'a [T],
+    actual: &'a [T],
+    trace: &[Vec<isize>],
+) -> Vec<Change<&'a T>> {
+    let mut changes = Vec::with_capacity(expected.len() + actual.len());
+    let (mut x, mut y) = (expected.len() as isize, actual.len() as isize);
+    for (d, furthest) in trace.iter().enumerate().rev() {
+        let d = d as isize;
+        // the values recorded before round d cover the diagonals -d..=d
+        let at = |k: isize| furthest[(k + d) as usize];
+        let k = x - y;
+        let previous_k = if k == -d || (k != d && at(k - 1) < at(k + 1)) {
+            k + 1
+        } else {
+            k - 1
+        };
+        let previous_x = if d == 0 { 0 } else { at(previous_k) };
+        let previous_y = if d == 0 { 0 } else { previous_x - previous_k };
+        while x > previous_x && y > previous_y {
+            x -= 1;
+            y -= 1;
+            changes.push(Change::Same(&expected[x as usize]));
+        }
+        if d > 0 {
+            if x == previous_x {
+                y -= 1;
+                changes.push(Change::Added(&actual[y as usize]));
+            } else {
+                x -= 1;
+                changes.push(Change::Removed(&expected[x as usize]));
+            }
+        }
+    }
+    changes.reverse();
+    changes
+}
+
+#[cfg(test)]
+mod tests;
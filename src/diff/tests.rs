@@ -0,0 +1,114 @@
+use super::*;
+use asserting::prelude::*;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+#[test]
+fn the_diff_of_two_sequences_marks_removed_and_added_items() {
+    let changes = diff(&[1, 2, 3, 4], &[1, 3, 5, 4]);
+
+    assert_that!(changes).is_equal_to(vec![
+        Change::Same(&1),
+        Change::Removed(&2),
+        Change::Same(&3),
+        Change::Added(&5),
+        Change::Same(&4),
+    ]);
+}
+
+#[test]
+fn the_diff_of_long_sequences_with_few_differences_lists_only_the_differing_items() {
+    let expected = (0..100_000).collect::<Vec<_>>();
+    let mut actual = expected.clone();
+    actual[10_000] = -1;
+    actual.remove(60_000);
+
+    let changes = diff(&expected, &actual);
+
+    assert_that!(changes.len()).is_equal_to(100_001);
+    assert_that!(changes
+        .iter()
+        .filter(|change| !matches!(change, Change::Same(_)))
+        .copied()
+        .collect::<Vec<_>>())
+    .is_equal_to(vec![
+        Change::Removed(&10_000),
+        Change::Added(&-1),
+        Change::Removed(&60_000),
+    ]);
+}
+
+#[test]
+fn sequences_differing_in_more_items_than_the_max_edit_distance_are_listed_as_removed_and_added() {
+    let expected = (0..100_000).collect::<Vec<_>>();
+    let actual = (0..100_000).map(|item| -item - 1).collect::<Vec<_>>();
+
+    let changes = diff(&expected, &actual);
+
+    assert_that!(changes[..100_000]
+        .iter()
+        .all(|change| matches!(change, Change::Removed(_))))
+    .is_true();
+    assert_that!(changes[100_000..]
+        .iter()
+        .all(|change| matches!(change, Change::Added(_))))
+    .is_true();
+}
+
+/// Computes the length of the longest common subsequence of two sequences.
+fn longest_common_subsequence(expected: &[u8], actual: &[u8]) -> usize {
+    let mut common = vec![vec![0_usize; actual.len() + 1]; expected.len() + 1];
+    for i in (0..expected.len()).rev() {
+        for j in (0..actual.len()).rev() {
+            common[i][j] = if expected[i] == actual[j] {
+                common[i + 1][j + 1] + 1
+            } else {
+                common[i + 1][j].max(common[i][j + 1])
+            };
+        }
+    }
+    common[0][0]
+}
+
+proptest! {
+    #[test]
+    fn the_diff_of_two_sequences_keeps_their_longest_common_subsequence(
+        expected in vec(0..3_u8, 0..30),
+        actual in vec(0..3_u8, 0..30),
+    ) {
+        let changes = diff(&expected, &actual);
+
+        let same = changes
+            .iter()
+            .filter(|change| matches!(change, Change::Same(_)))
+            .count();
+
+        prop_assert_eq!(same, longest_common_subsequence(&expected, &actual));
+    }
+
+    #[test]
+    fn the_diff_of_two_sequences_contains_all_items_of_both_sequences_in_order(
+        expected in vec(0..3_u8, 0..12),
+        actual in vec(0..3_u8, 0..12),
+    ) {
+        let changes = diff(&expected, &actual);
+
+        let old_side = changes
+            .iter()
+            .filter_map(|change| match change {
+                Change::Same(item) | Change::Removed(item) => Some(**item),
+                Change::Added(_) => None,
+            })
+            .collect::<Vec<_>>();
+        let new_side = changes
+            .iter()
+            .filter_map(|change| match change {
+                Change::Same(item) | Change::Added(item) => Some(**item),
+                Change::Removed(_) => None,
+            })
+            .collect::<Vec<_>>();
+
+        prop_assert_eq!(old_side, expected);
+        prop_assert_eq!(new_side, actual);
+    }
+}
@@ -0,0 +1,82 @@
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+use std::collections::VecDeque;
+use std::fmt::{self, Debug};
+#[cfg(feature = "serde")]
+use std::io::BufRead;
+
+pub struct BasicResponses<T> {
+    adapter: String,
+    responses: Responses<T>,
+}
+
+enum Responses<T> {
+    Always { response: T, clone: fn(&T) -> T },
+    Sequence(VecDeque<T>),
+}
+
+impl<T> Debug for BasicResponses<T>
+where
+    T: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("BasicResponses");
+        debug.field("adapter", &self.adapter);
+        match &self.responses {
+            Responses::Always { response, .. } => debug.field("always", response),
+            Responses::Sequence(responses) => debug.field("sequence", responses),
+        };
+        debug.finish()
+    }
+}
+
+impl<T> BasicResponses<T> {
+    pub fn always(adapter: String, response: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            adapter,
+            responses: Responses::Always {
+                response,
+                clone: T::clone,
+            },
+        }
+    }
+
+    pub fn sequence(adapter: String, responses: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            adapter,
+            responses: Responses::Sequence(responses.into_iter().collect()),
+        }
+    }
+
+    #[cfg(feature = "serde")]
+    pub fn replay(adapter: String, recording: impl BufRead) -> Result<Self, serde_json::Error>
+    where
+        T: DeserializeOwned,
+    {
+        let mut responses = VecDeque::new();
+        for line in recording.lines() {
+            let line = line.map_err(serde_json::Error::io)?;
+            if !line.trim().is_empty() {
+                responses.push_back(serde_json::from_str(&line)?);
+            }
+        }
+        Ok(Self {
+            adapter,
+            responses: Responses::Sequence(responses),
+        })
+    }
+
+    pub fn adapter(&self) -> &str {
+        &self.adapter
+    }
+
+    pub fn next(&mut self) -> Option<T> {
+        match &mut self.responses {
+            Responses::Always { response, clone } => Some(clone(response)),
+            Responses::Sequence(responses) => responses.pop_front(),
+        }
+    }
+}
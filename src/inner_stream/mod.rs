@@ -0,0 +1,63 @@
+use std::collections::VecDeque;
+use std::task::{Context, Poll, Waker};
+
+#[derive(Debug)]
+pub struct BasicStream<M> {
+    queue: VecDeque<M>,
+    waker: Option<Waker>,
+    closed: bool,
+}
+
+impl<M> Default for BasicStream<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> BasicStream<M> {
+    pub const fn new() -> Self {
+        Self {
+            queue: VecDeque::new(),
+            waker: None,
+            closed: false,
+        }
+    }
+
+    pub fn push(&mut self, data: M) {
+        self.queue.push_back(data);
+        self.wake();
+    }
+
+    pub fn close(&mut self) {
+        self.closed = true;
+        self.wake();
+    }
+
+    pub fn poll_next(&mut self, cx: &Context<'_>) -> Poll<Option<M>> {
+        if let Some(data) = self.queue.pop_front() {
+            return Poll::Ready(Some(data));
+        }
+        if self.closed {
+            return Poll::Ready(None);
+        }
+        if !self
+            .waker
+            .as_ref()
+            .is_some_and(|waker| waker.will_wake(cx.waker()))
+        {
+            self.waker = Some(cx.waker().clone());
+        }
+        Poll::Pending
+    }
+
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        let queued = self.queue.len();
+        (queued, self.closed.then_some(queued))
+    }
+
+    fn wake(&mut self) {
+        if let Some(waker) = self.waker.take() {
+            waker.wake();
+        }
+    }
+}
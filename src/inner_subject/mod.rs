@@ -1,5 +1,6 @@
-use crate::inner_tracker::CelledTracker;
+use crate::record::Emission;
 use crate::tracker_handle::TrackerHandle;
+use std::fmt::{self, Debug};
 use std::marker::PhantomData;
 use std::ops::{Deref, DerefMut};
 use std::slice;
@@ -17,10 +18,7 @@ pub trait CelledSubject<M, T> {
 
     fn subject_mut(&self) -> Result<Self::InnerMut<'_>, Self::Error>;
 
-    fn add_tracker(&self, tracker: T) -> Result<TrackerHandle, Self::Error>
-    where
-        T: CelledTracker<M>,
-    {
+    fn add_tracker(&self, tracker: T) -> Result<TrackerHandle, Self::Error> {
         self.subject_mut()
             .map(|mut subject| subject.add_tracker(tracker))
     }
@@ -30,25 +28,56 @@ pub trait CelledSubject<M, T> {
             .map(|mut subject| subject.remove_tracker(tracker))
     }
 
+    fn emission(&self) -> Emission<'_> {
+        Emission::new()
+    }
+
     fn emit(&self, data: M) -> Result<(), Self::Error>
     where
-        M: Clone,
-        T: CelledTracker<M>,
-        Self::Error: From<<T as CelledTracker<M>>::Error>,
+        T: Fn(&M, &Emission<'_>) -> Result<(), Self::Error>,
     {
+        let emission = self.emission();
+        let mut result = Ok(());
         for tracker in self.subject()?.trackers() {
-            tracker.track(data.clone())?;
+            // deliver the data to all trackers even if one of them fails
+            let tracked = tracker(&data, &emission);
+            if result.is_ok() {
+                result = tracked;
+            }
         }
-        Ok(())
+        // releases the order of the emission after the data has been
+        // delivered to all trackers
+        drop(emission);
+        result
     }
 }
 
-#[derive(Debug)]
+pub trait Unsubscribe {
+    type Error;
+
+    fn unsubscribe(&self, tracker: TrackerHandle) -> Result<(), Self::Error>;
+}
+
 pub struct BasicSubject<M, T> {
-    _data: PhantomData<M>,
+    _data: PhantomData<fn(&M)>,
     trackers: Vec<(TrackerHandle, T)>,
 }
 
+impl<M, T> Debug for BasicSubject<M, T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BasicSubject")
+            .field(
+                "trackers",
+                &self
+                    .trackers
+                    .iter()
+                    .map(|(handle, _)| handle)
+                    .collect::<Vec<_>>(),
+            )
+            .finish()
+    }
+}
+
 impl<M, T> Default for BasicSubject<M, T> {
     fn default() -> Self {
         Self::new()
@@ -1,7 +1,20 @@
+use crate::tracker_config::{OverflowPolicy, TrackerConfig};
+use crate::tracker_snapshot::TrackerSnapshot;
+use crate::tracking_scope::current_correlation_id;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+use std::collections::{vec_deque, VecDeque};
+#[cfg(feature = "serde")]
+use std::fs::File;
+#[cfg(feature = "serde")]
+use std::io::{self, BufWriter, Write};
 use std::ops::{Deref, DerefMut};
+#[cfg(feature = "serde")]
+use std::path::Path;
+use std::sync::Arc;
 
 pub trait Tracker<M> {
-    fn track(&mut self, data: M);
+    fn track(&mut self, data: M) -> Result<(), CapacityExceeded>;
 }
 
 pub trait CelledTracker<M> {
@@ -11,9 +24,9 @@ pub trait CelledTracker<M> {
     type InnerMut<'a>: DerefMut<Target = BasicTracker<M>>
     where
         Self: 'a;
-    type Error: std::error::Error;
+    type Error: std::error::Error + From<CapacityExceeded>;
 
-    fn new() -> Self;
+    fn new(config: TrackerConfig) -> Self;
 
     fn tracker(&self) -> Result<Self::Inner<'_>, Self::Error>;
 
@@ -23,7 +36,86 @@ pub trait CelledTracker<M> {
     where
         M: Clone,
     {
-        self.tracker().map(|tracker| tracker.output().to_vec())
+        self.tracker()
+            .map(|tracker| tracker.output().iter().cloned().collect())
+    }
+
+    fn output_from(&self, position: usize) -> Result<(Vec<M>, usize), Self::Error>
+    where
+        M: Clone,
+    {
+        self.tracker().map(|tracker| {
+            (
+                tracker.output_from(position).cloned().collect(),
+                tracker.end_position(),
+            )
+        })
+    }
+
+    fn output_for(&self, correlation_id: &str) -> Result<Vec<M>, Self::Error>
+    where
+        M: Clone,
+    {
+        self.tracker()
+            .map(|tracker| tracker.output_for(correlation_id).cloned().collect())
+    }
+
+    fn snapshot(&self) -> Result<TrackerSnapshot<M>, Self::Error>
+    where
+        M: Clone,
+    {
+        self.tracker().map(|tracker| TrackerSnapshot {
+            output: tracker.output().iter().cloned().collect(),
+            dropped_count: tracker.dropped_count(),
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    fn output_json(&self) -> Result<String, Self::Error>
+    where
+        M: Serialize,
+        Self::Error: From<serde_json::Error>,
+    {
+        self.tracker()
+            .and_then(|tracker| serde_json::to_string(tracker.output()).map_err(Self::Error::from))
+    }
+
+    #[cfg(feature = "serde")]
+    fn export_jsonl(&self, mut writer: impl Write) -> Result<(), Self::Error>
+    where
+        M: Serialize,
+        Self::Error: From<serde_json::Error>,
+    {
+        self.tracker().and_then(|tracker| {
+            tracker
+                .output()
+                .iter()
+                .try_for_each(|data| {
+                    serde_json::to_writer(&mut writer, data)?;
+                    writer.write_all(b"\n").map_err(serde_json::Error::io)
+                })
+                .map_err(Self::Error::from)
+        })
+    }
+
+    #[cfg(feature = "serde")]
+    fn save_recording(&self, path: &Path) -> Result<(), Self::Error>
+    where
+        M: Serialize,
+        Self::Error: From<serde_json::Error> + From<io::Error>,
+    {
+        let file = File::create(path)?;
+        let mut writer = BufWriter::new(file);
+        self.export_jsonl(&mut writer)?;
+        writer.flush().map_err(Self::Error::from)
+    }
+
+    fn drain(&self) -> Result<Vec<M>, Self::Error> {
+        self.tracker_mut().map(|mut tracker| tracker.drain())
+    }
+
+    fn dropped_count(&self) -> Result<usize, Self::Error> {
+        self.tracker().map(|tracker| tracker.dropped_count())
     }
 
     fn clear(&self) -> Result<(), Self::Error> {
@@ -31,36 +123,104 @@ pub trait CelledTracker<M> {
     }
 
     fn track(&self, data: M) -> Result<(), Self::Error> {
-        self.tracker_mut().map(|mut tracker| tracker.track(data))
+        self.tracker_mut()
+            .and_then(|mut tracker| tracker.track(data).map_err(Self::Error::from))
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CapacityExceeded {
+    pub capacity: usize,
+}
+
 #[derive(Debug)]
 pub struct BasicTracker<M> {
-    tracked: Vec<M>,
+    tracked: VecDeque<M>,
+    // correlation ids of the tracked items, one entry for each item in `tracked`
+    correlation_ids: VecDeque<Option<Arc<str>>>,
+    config: TrackerConfig,
+    dropped: usize,
+    // number of items that have been removed from the front of `tracked`
+    offset: usize,
 }
 
 impl<M> BasicTracker<M> {
-    pub const fn new() -> Self {
+    pub const fn new(config: TrackerConfig) -> Self {
         Self {
-            tracked: Vec::new(),
+            tracked: VecDeque::new(),
+            correlation_ids: VecDeque::new(),
+            config,
+            dropped: 0,
+            offset: 0,
         }
     }
 
-    pub fn output(&self) -> &[M]
-    where
-        M: Clone,
-    {
+    pub const fn output(&self) -> &VecDeque<M> {
         &self.tracked
     }
 
+    #[cfg(feature = "_threadsafe-variant")]
+    pub fn output_slice(&mut self) -> &[M] {
+        self.tracked.make_contiguous()
+    }
+
+    pub fn output_from(&self, position: usize) -> vec_deque::Iter<'_, M> {
+        let start = position.saturating_sub(self.offset).min(self.tracked.len());
+        self.tracked.range(start..)
+    }
+
+    pub fn output_for<'a>(&'a self, correlation_id: &'a str) -> impl Iterator<Item = &'a M> {
+        self.tracked
+            .iter()
+            .zip(&self.correlation_ids)
+            .filter(move |(_, id)| id.as_deref() == Some(correlation_id))
+            .map(|(data, _)| data)
+    }
+
+    pub fn end_position(&self) -> usize {
+        self.offset + self.tracked.len()
+    }
+
+    pub const fn dropped_count(&self) -> usize {
+        self.dropped
+    }
+
+    pub fn drain(&mut self) -> Vec<M> {
+        self.offset += self.tracked.len();
+        self.correlation_ids.clear();
+        self.tracked.drain(..).collect()
+    }
+
     pub fn clear(&mut self) {
+        self.offset += self.tracked.len();
         self.tracked.clear();
+        self.correlation_ids.clear();
+        self.dropped = 0;
     }
 }
 
 impl<M> Tracker<M> for BasicTracker<M> {
-    fn track(&mut self, data: M) {
-        self.tracked.push(data);
+    fn track(&mut self, data: M) -> Result<(), CapacityExceeded> {
+        if self.tracked.len() < self.config.capacity {
+            self.tracked.push_back(data);
+            self.correlation_ids.push_back(current_correlation_id());
+            return Ok(());
+        }
+        self.dropped += 1;
+        match self.config.overflow {
+            OverflowPolicy::DropOldest => {
+                if self.tracked.pop_front().is_some() {
+                    _ = self.correlation_ids.pop_front();
+                    self.offset += 1;
+                    self.tracked.push_back(data);
+                    self.correlation_ids.push_back(current_correlation_id());
+                }
+                Ok(())
+            },
+            OverflowPolicy::DropNewest => Ok(()),
+            OverflowPolicy::Error => Err(CapacityExceeded {
+                capacity: self.config.capacity,
+            }),
+        }
     }
 }
@@ -403,6 +403,46 @@
 //! See the integration tests of this crate as they demonstrate the usage of
 //! output-tracker in a more involved and complete way.
 //!
+//! ## Configurable responses
+//!
+//! The other half of the nullables pattern are configurable responses. A
+//! nulled infrastructure wrapper returns responses configured by the test
+//! instead of talking to the outside world. The `ConfigurableResponses` type
+//! holds either a single response that is returned every time or a list of
+//! responses that are returned one after another:
+//!
+//! ```
+//! use output_tracker::non_threadsafe::ConfigurableResponses;
+//!
+//! struct NulledMail {
+//!     responses: ConfigurableResponses<Result<(), String>>,
+//! }
+//!
+//! impl NulledMail {
+//!     fn send_mail(&self) -> Result<(), String> {
+//!         self.responses
+//!             .next()
+//!             .unwrap_or_else(|err| panic!("could not get response: {err}"))
+//!     }
+//! }
+//!
+//! let nulled_mail = NulledMail {
+//!     responses: ConfigurableResponses::sequence(
+//!         "NulledMail",
+//!         [Ok(()), Err("mailbox is full".to_string())],
+//!     ),
+//! };
+//!
+//! assert_eq!(nulled_mail.send_mail(), Ok(()));
+//! assert_eq!(nulled_mail.send_mail(), Err("mailbox is full".to_string()));
+//! ```
+//!
+//! With the crate feature `serde` enabled, realistic responses can be recorded
+//! from the real infrastructure once and replayed in tests afterward. The
+//! responses tracked by an `OutputTracker` are saved to a file with
+//! `OutputTracker::save_recording(path)` and loaded as configured responses
+//! with `ConfigurableResponses::load_recording(adapter, path)`.
+//!
 //! ## Threadsafe and non-threadsafe variants
 //!
 //! The output-tracker functionality is provided in a non-threadsafe variant and
@@ -415,25 +455,63 @@
 //! By default, only the non-threadsafe variant is compiled. One can activate
 //! only one variant or both variants as needed. If the feature `threadsafe` is
 //! specified, only the threadsafe variant is compiled. To use both variants at
-//! the same time both features must be specified. The crate features and the
-//! variants which are activated by each feature are listed in the table below.
+//! the same time both features must be specified. The features `async` and
+//! `nullables` compile the threadsafe variant as well, but do not remove the
+//! non-threadsafe one. The crate features and the variants which are activated
+//! by each feature are listed in the table below.
 //!
 //! | Crate feature    | Variant        | Rust module import                                        |
 //! |:-----------------|:---------------|:----------------------------------------------------------|
 //! | `non-threadsafe` | non-threadsafe | [`use output_tracker::non_threadsafe::*`][non_threadsafe] |
 //! | `threadsafe`     | threadsafe     | [`use output_tracker::threadsafe::*`][threadsafe]         |
 //!
+//! ## Optional crate features
+//!
+//! Additional functionality is gated behind the following crate features:
+//!
+//! | Crate feature    | Description                                                                                                                                                                                                            |
+//! |:-----------------|:-----------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------------|
+//! | `asserting`      | Assertions like `has_tracked_exactly` for `OutputTracker`s integrated with the [`asserting`](https://crates.io/crates/asserting) crate via the trait `assertions::AssertOutputTracker`.                                |
+//! | `async`          | Futures for awaiting tracked data in the threadsafe variant. Compiles the threadsafe variant, but keeps the non-threadsafe one.                                                                                        |
+//! | `derive`         | Derive macro `Tracked` and attribute macro `tracked` for nullable adapters.                                                                                                                                            |
+//! | `nullables`      | Nullable infrastructure wrappers in the module `nullables`. Compiles the threadsafe variant, but keeps the non-threadsafe one.                                                                                         |
+//! | `nullables-fs`   | Nullable file system wrapper `nullables::FileSystem`. Implies the `nullables` feature.                                                                                                                                 |
+//! | `nullables-http` | Nullable HTTP client `nullables::HttpClient` with an adapter trait for the HTTP library of choice. Implies the `nullables` feature.                                                                                    |
+//! | `serde`          | Export of the tracked data as JSON via `OutputTracker::output_json()` and `OutputTracker::export_jsonl()`; serializable `TrackerSnapshot`; replaying recorded responses via `ConfigurableResponses::load_recording()`. |
+//! | `snapshot`       | Snapshot testing of the output of a tracker against golden files via the macro `assert_output_snapshot!`.                                                                                                              |
+//! | `stream`         | Streams yielding emitted data as it arrives, created by `OutputSubject::subscribe_stream()`.                                                                                                                           |
+//!
 //! [nullables]: https://www.jamesshore.com/v2/projects/nullables
 
 #![doc(html_root_url = "https://docs.rs/output-tracker/0.1.1")]
 
+#[cfg(feature = "asserting")]
+pub mod assertions;
+#[cfg(any(feature = "asserting", feature = "snapshot"))]
+mod diff;
+mod inner_responses;
+#[cfg(feature = "stream")]
+mod inner_stream;
 mod inner_subject;
 mod inner_tracker;
+pub mod matching;
 #[cfg(any(feature = "non-threadsafe", not(feature = "threadsafe")))]
 pub mod non_threadsafe;
-#[cfg(feature = "threadsafe")]
+#[cfg(feature = "nullables")]
+pub mod nullables;
+mod record;
+#[cfg(feature = "snapshot")]
+pub mod snapshot;
+#[cfg(feature = "_threadsafe-variant")]
 pub mod threadsafe;
+mod tracker_config;
 mod tracker_handle;
+mod tracker_snapshot;
+mod tracking_scope;
+mod unordered_output;
+
+#[cfg(feature = "derive")]
+pub use output_tracker_derive::{tracked, Tracked};
 
 // test code snippets in the README.md
 #[cfg(doctest)]
@@ -445,5 +523,7 @@ type TestExamplesInReadme = ();
 // Rust issue [#95513](https://github.com/rust-lang/rust/issues/95513) is fixed
 #[cfg(test)]
 mod dummy_extern_uses {
+    use criterion as _;
+    use futures as _;
     use version_sync as _;
 }
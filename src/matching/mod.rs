@@ -0,0 +1,539 @@
+//! Pattern matching on the data collected by an output-tracker.
+//!
+//! Asserting the exact output of a tracker is not always feasible. Often a
+//! test is interested in the shape of the output, like "an insert, then any
+//! number of updates, then a commit". Such shapes are described by
+//! [`Element`]s, which are combined into matchers:
+//!
+//! * [`seq!`] matches the whole output against a sequence of elements.
+//! * [`contains_subsequence()`] matches elements in the given order while
+//!   other items may be tracked before, after or between them.
+//! * [`never()`] verifies that no tracked item matches an element.
+//!
+//! An element matches a single item. It is created by one of the functions
+//! [`is()`], [`satisfies()`] or [`anything()`] or by the macro [`like!`]. The
+//! functions [`any_number_of()`] and [`one_or_more()`] repeat an element.
+//!
+//! A matcher verifies the output of a tracker via [`Matcher::verify()`]. If
+//! the output does not match, the returned [`Mismatch`] points at the first
+//! index where the output diverges from the pattern.
+//!
+//! # Example
+//!
+//! ```
+//! use output_tracker::matching::{any_number_of, is, never, Matcher};
+//! use output_tracker::non_threadsafe::OutputSubject;
+//! use output_tracker::{like, seq};
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! enum DbAction {
+//!     Insert { id: u32 },
+//!     Update { id: u32 },
+//!     Commit,
+//!     Rollback,
+//! }
+//!
+//! let subject = OutputSubject::new();
+//! let tracker = subject.create_tracker().unwrap();
+//!
+//! subject.emit(DbAction::Insert { id: 1 }).unwrap();
+//! subject.emit(DbAction::Update { id: 1 }).unwrap();
+//! subject.emit(DbAction::Update { id: 1 }).unwrap();
+//! subject.emit(DbAction::Commit).unwrap();
+//!
+//! let output = tracker.output().unwrap();
+//!
+//! let transaction = seq![
+//!     like!(DbAction::Insert { .. }),
+//!     any_number_of(like!(DbAction::Update { .. })),
+//!     is(DbAction::Commit),
+//! ];
+//! assert_eq!(transaction.verify(&output), Ok(()));
+//! assert_eq!(never(is(DbAction::Rollback)).verify(&output), Ok(()));
+//!
+//! let mismatch = seq![like!(DbAction::Insert { .. }), is(DbAction::Commit)]
+//!     .verify(&output)
+//!     .unwrap_err();
+//! assert_eq!(mismatch.index(), 1);
+//! assert_eq!(
+//!     mismatch.to_string(),
+//!     "output does not match at index 1: expected Commit, but found Update { id: 1 }"
+//! );
+//! ```
+//!
+//! [`seq!`]: crate::seq
+//! [`like!`]: crate::like
+
+use std::fmt::{self, Debug, Display};
+
+/// A pattern for a single tracked item.
+///
+/// By default, an element matches exactly one item. The functions
+/// [`any_number_of()`] and [`one_or_more()`] turn an element into one that
+/// matches a run of items.
+pub struct Element<M> {
+    description: String,
+    predicate: Box<dyn Fn(&M) -> bool>,
+    min: usize,
+    max: Option<usize>,
+}
+
+impl<M> Debug for Element<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Element")
+            .field("description", &self.description)
+            .field("min", &self.min)
+            .field("max", &self.max)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M> Display for Element<M> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.description)
+    }
+}
+
+impl<M> Element<M> {
+    fn matches(&self, item: &M) -> bool {
+        (self.predicate)(item)
+    }
+
+    fn accepts_count(&self, count: usize) -> bool {
+        self.max.is_none_or(|max| count <= max)
+    }
+}
+
+/// Creates an element matching an item equal to the given value.
+#[must_use]
+pub fn is<M>(expected: M) -> Element<M>
+where
+    M: PartialEq + Debug + 'static,
+{
+    Element {
+        description: format!("{expected:?}"),
+        predicate: Box::new(move |item| *item == expected),
+        min: 1,
+        max: Some(1),
+    }
+}
+
+/// Creates an element matching an item for which the given predicate returns
+/// true.
+///
+/// The description is used in the report of a mismatch.
+pub fn satisfies<M>(
+    description: impl Into<String>,
+    predicate: impl Fn(&M) -> bool + 'static,
+) -> Element<M> {
+    Element {
+        description: description.into(),
+        predicate: Box::new(predicate),
+        min: 1,
+        max: Some(1),
+    }
+}
+
+/// Creates an element matching any item.
+#[must_use]
+pub fn anything<M>() -> Element<M> {
+    satisfies("any item", |_| true)
+}
+
+/// Repeats the given element, so that it matches zero or more consecutive
+/// items.
+#[must_use]
+pub fn any_number_of<M>(element: Element<M>) -> Element<M> {
+    Element {
+        description: format!("any number of {}", element.description),
+        min: 0,
+        max: None,
+        ..element
+    }
+}
+
+/// Repeats the given element, so that it matches one or more consecutive
+/// items.
+#[must_use]
+pub fn one_or_more<M>(element: Element<M>) -> Element<M> {
+    Element {
+        description: format!("one or more of {}", element.description),
+        min: 1,
+        max: None,
+        ..element
+    }
+}
+
+/// Verifies the data collected by an output-tracker.
+pub trait Matcher<M> {
+    /// Verifies whether the given output matches.
+    ///
+    /// Returns a [`Mismatch`] pointing at the first index where the output
+    /// diverges from the expected pattern.
+    fn verify(&self, output: &[M]) -> Result<(), Mismatch>;
+}
+
+/// Matches the whole output against a sequence of elements.
+///
+/// Created by the macro [`seq!`](crate::seq).
+#[derive(Debug)]
+pub struct Sequence<M> {
+    elements: Vec<Element<M>>,
+}
+
+impl<M> Sequence<M> {
+    /// Constructs a sequence matcher from the given elements.
+    pub fn new(elements: impl IntoIterator<Item = Element<M>>) -> Self {
+        Self {
+            elements: elements.into_iter().collect(),
+        }
+    }
+}
+
+impl<M> Matcher<M> for Sequence<M>
+where
+    M: Debug,
+{
+    fn verify(&self, output: &[M]) -> Result<(), Mismatch> {
+        let mut search = Search::new(&self.elements, output, false);
+        if search.matches(0, 0) {
+            Ok(())
+        } else {
+            Err(search.divergence.into_mismatch(output))
+        }
+    }
+}
+
+/// Matches elements in the given order while other items may be tracked
+/// before, after or between them.
+///
+/// Created by the function [`contains_subsequence()`].
+#[derive(Debug)]
+pub struct Subsequence<M> {
+    elements: Vec<Element<M>>,
+}
+
+/// Creates a matcher verifying that the output contains items matching the
+/// given elements in the given order.
+///
+/// Other items may be tracked before, after or between the matched items. A
+/// repeated element matches consecutive items only.
+pub fn contains_subsequence<M>(elements: impl IntoIterator<Item = Element<M>>) -> Subsequence<M> {
+    Subsequence {
+        elements: elements.into_iter().collect(),
+    }
+}
+
+impl<M> Matcher<M> for Subsequence<M>
+where
+    M: Debug,
+{
+    fn verify(&self, output: &[M]) -> Result<(), Mismatch> {
+        let mut search = Search::new(&self.elements, output, true);
+        if search.matches(0, 0) {
+            Ok(())
+        } else {
+            let (element, index) = search.progress;
+            Err(Mismatch {
+                index,
+                reason: Reason::NotFound {
+                    expected: self.elements[element].description.clone(),
+                },
+            })
+        }
+    }
+}
+
+/// Verifies that no item matches an element.
+///
+/// Created by the function [`never()`].
+#[derive(Debug)]
+pub struct Never<M> {
+    element: Element<M>,
+}
+
+/// Creates a matcher verifying that no item of the output matches the given
+/// element.
+#[must_use]
+pub const fn never<M>(element: Element<M>) -> Never<M> {
+    Never { element }
+}
+
+impl<M> Matcher<M> for Never<M>
+where
+    M: Debug,
+{
+    fn verify(&self, output: &[M]) -> Result<(), Mismatch> {
+        output
+            .iter()
+            .position(|item| self.element.matches(item))
+            .map_or(Ok(()), |index| {
+                Err(Mismatch {
+                    index,
+                    reason: Reason::Forbidden {
+                        unexpected: self.element.description.clone(),
+                        found: format!("{:?}", output[index]),
+                    },
+                })
+            })
+    }
+}
+
+/// Describes where and why the output does not match a [`Matcher`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Mismatch {
+    index: usize,
+    reason: Reason,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Reason {
+    Unexpected {
+        expected: Vec<String>,
+        found: Option<String>,
+    },
+    NotFound {
+        expected: String,
+    },
+    Forbidden {
+        unexpected: String,
+        found: String,
+    },
+}
+
+impl Mismatch {
+    /// Returns the index of the first item where the output diverges from
+    /// the expected pattern.
+    ///
+    /// If the output ended before the pattern was complete, the index is the
+    /// length of the output.
+    #[must_use]
+    pub const fn index(&self) -> usize {
+        self.index
+    }
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let index = self.index;
+        match &self.reason {
+            Reason::Unexpected { expected, found } => {
+                let expected = if expected.is_empty() {
+                    "the end of the output".to_string()
+                } else {
+                    expected.join(" or ")
+                };
+                match found {
+                    Some(found) => write!(
+                        f,
+                        "output does not match at index {index}: expected {expected}, but found \
+                         {found}"
+                    ),
+                    None => write!(
+                        f,
+                        "output does not match at index {index}: expected {expected}, but the \
+                         output ended"
+                    ),
+                }
+            },
+            Reason::NotFound { expected } => write!(
+                f,
+                "output does not contain the subsequence: expected {expected} at or after index \
+                 {index}, but it was not found"
+            ),
+            Reason::Forbidden { unexpected, found } => write!(
+                f,
+                "output contains a forbidden item at index {index}: expected never {unexpected}, \
+                 but found {found}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// The furthest index the search reached along with the descriptions of the
+/// elements that were expected at this index.
+struct Divergence {
+    index: usize,
+    expected: Vec<String>,
+}
+
+impl Divergence {
+    fn record(&mut self, index: usize, expected: Option<&str>) {
+        if index > self.index {
+            self.index = index;
+            self.expected.clear();
+        }
+        if index == self.index {
+            if let Some(expected) = expected {
+                if !self.expected.iter().any(|known| known == expected) {
+                    self.expected.push(expected.to_owned());
+                }
+            }
+        }
+    }
+
+    fn into_mismatch<M>(self, output: &[M]) -> Mismatch
+    where
+        M: Debug,
+    {
+        Mismatch {
+            index: self.index,
+            reason: Reason::Unexpected {
+                expected: self.expected,
+                found: output.get(self.index).map(|item| format!("{item:?}")),
+            },
+        }
+    }
+}
+
+/// Backtracking search for a match of the elements against the output.
+struct Search<'a, M> {
+    elements: &'a [Element<M>],
+    output: &'a [M],
+    // whether items not matched by any element may be skipped
+    skip_unmatched: bool,
+    // states (element, index) that are known to not lead to a match
+    failed: Vec<bool>,
+    // for each element the index from which on skipping items is known to not
+    // lead to a match
+    exhausted: Vec<usize>,
+    divergence: Divergence,
+    // the most advanced element reached and the index where it was reached
+    progress: (usize, usize),
+}
+
+impl<'a, M> Search<'a, M> {
+    fn new(elements: &'a [Element<M>], output: &'a [M], skip_unmatched: bool) -> Self {
+        Self {
+            elements,
+            output,
+            skip_unmatched,
+            failed: vec![false; (elements.len() + 1) * (output.len() + 1)],
+            exhausted: vec![output.len() + 1; elements.len() + 1],
+            divergence: Divergence {
+                index: 0,
+                expected: Vec::new(),
+            },
+            progress: (0, 0),
+        }
+    }
+
+    fn matches(&mut self, element: usize, index: usize) -> bool {
+        if !self.skip_unmatched || element == self.elements.len() {
+            return self.matches_at(element, index);
+        }
+        // skipping is done in a loop, as the output may be too long to
+        // recurse once per skipped item
+        let mut start = index;
+        while start < self.exhausted[element] {
+            if self.matches_at(element, start) {
+                return true;
+            }
+            start += 1;
+        }
+        self.exhausted[element] = self.exhausted[element].min(index);
+        false
+    }
+
+    fn matches_at(&mut self, element: usize, index: usize) -> bool {
+        let state = element * (self.output.len() + 1) + index;
+        if self.failed[state] {
+            return false;
+        }
+        if element > self.progress.0 {
+            self.progress = (element, index);
+        }
+        let matched = self.matches_element(element, index);
+        if !matched {
+            self.failed[state] = true;
+        }
+        matched
+    }
+
+    fn matches_element(&mut self, element: usize, index: usize) -> bool {
+        let Some(current) = self.elements.get(element) else {
+            if index == self.output.len() || self.skip_unmatched {
+                return true;
+            }
+            self.divergence.record(index, None);
+            return false;
+        };
+
+        // count the consecutive items matched by the current element
+        let mut count = 0;
+        while index + count < self.output.len()
+            && current.accepts_count(count + 1)
+            && current.matches(&self.output[index + count])
+        {
+            count += 1;
+        }
+        if current.accepts_count(count + 1) {
+            self.divergence
+                .record(index + count, Some(&current.description));
+        }
+        if count < current.min {
+            return false;
+        }
+
+        // prefer matching as many items as possible
+        (current.min..=count)
+            .rev()
+            .any(|taken| self.matches(element + 1, index + taken))
+    }
+}
+
+/// Creates a [`Sequence`] matcher from a list of [`Element`]s.
+///
+/// The sequence matches if the whole output matches the elements in the
+/// given order.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::matching::{is, one_or_more, Matcher};
+/// use output_tracker::seq;
+///
+/// let pattern = seq![is("open"), one_or_more(is("write")), is("close")];
+///
+/// assert!(pattern.verify(&["open", "write", "write", "close"]).is_ok());
+/// assert!(pattern.verify(&["open", "close"]).is_err());
+/// ```
+#[macro_export]
+macro_rules! seq {
+    ($($element:expr),* $(,)?) => {
+        $crate::matching::Sequence::new([$($element),*])
+    };
+}
+
+/// Creates an [`Element`] matching an item against a pattern.
+///
+/// The syntax is the same as for the second argument of the [`matches!`]
+/// macro of the standard library, including optional `if` guards. The
+/// pattern is used as the description of the element in the report of a
+/// mismatch.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::matching::{any_number_of, Matcher};
+/// use output_tracker::{like, seq};
+///
+/// let pattern = seq![any_number_of(like!(Some(n) if *n > 2)), like!(None)];
+///
+/// assert!(pattern.verify(&[Some(3), Some(4), None]).is_ok());
+/// assert!(pattern.verify(&[Some(3), Some(1), None]).is_err());
+/// ```
+#[macro_export]
+macro_rules! like {
+    ($pattern:pat $(if $guard:expr)? $(,)?) => {
+        $crate::matching::satisfies(
+            ::core::stringify!($pattern $(if $guard)?),
+            |item| ::core::matches!(item, $pattern $(if $guard)?),
+        )
+    };
+}
+
+#[cfg(test)]
+mod tests;
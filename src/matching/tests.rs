@@ -0,0 +1,243 @@
+use super::*;
+use asserting::prelude::*;
+use proptest::collection::vec;
+use proptest::prelude::*;
+
+#[derive(Debug, Clone, PartialEq)]
+enum DbAction {
+    Insert { id: u32 },
+    Update { id: u32 },
+    Commit,
+    Rollback,
+}
+
+use DbAction::{Commit, Insert, Rollback, Update};
+
+fn transaction() -> Sequence<DbAction> {
+    seq![
+        like!(Insert { .. }),
+        any_number_of(like!(Update { .. })),
+        is(Commit),
+    ]
+}
+
+#[test]
+fn a_sequence_matches_output_with_any_number_of_repeated_items() {
+    let pattern = transaction();
+
+    assert_that!(pattern.verify(&[Insert { id: 1 }, Commit])).is_ok();
+    assert_that!(pattern.verify(&[Insert { id: 1 }, Update { id: 1 }, Commit])).is_ok();
+    assert_that!(pattern.verify(&[Insert { id: 1 }, Update { id: 1 }, Update { id: 2 }, Commit]))
+        .is_ok();
+}
+
+#[test]
+fn a_sequence_reports_the_first_diverging_index_and_the_expected_elements() {
+    let mismatch = transaction()
+        .verify(&[Insert { id: 1 }, Update { id: 1 }, Rollback])
+        .err();
+
+    assert_that!(mismatch.as_ref().map(Mismatch::index)).is_equal_to(Some(2));
+    assert_that!(mismatch.map(|mismatch| mismatch.to_string())).is_equal_to(Some(
+        "output does not match at index 2: expected any number of Update { .. } or Commit, but \
+         found Rollback"
+            .to_string(),
+    ));
+}
+
+#[test]
+fn a_sequence_reports_when_the_output_ended_before_the_pattern_is_complete() {
+    let mismatch = transaction()
+        .verify(&[Insert { id: 1 }, Update { id: 1 }])
+        .err();
+
+    assert_that!(mismatch.map(|mismatch| mismatch.to_string())).is_equal_to(Some(
+        "output does not match at index 2: expected any number of Update { .. } or Commit, but \
+         the output ended"
+            .to_string(),
+    ));
+}
+
+#[test]
+fn a_sequence_reports_items_after_the_end_of_the_pattern() {
+    let mismatch = seq![is(Commit)].verify(&[Commit, Commit]).err();
+
+    assert_that!(mismatch.map(|mismatch| mismatch.to_string())).is_equal_to(Some(
+        "output does not match at index 1: expected the end of the output, but found Commit"
+            .to_string(),
+    ));
+}
+
+#[test]
+fn a_repeated_element_gives_back_items_needed_by_following_elements() {
+    let pattern = seq![
+        any_number_of(anything()),
+        is(Commit),
+        one_or_more(anything())
+    ];
+
+    assert_that!(pattern.verify(&[Commit, Insert { id: 1 }, Commit, Rollback])).is_ok();
+    assert_that!(pattern.verify(&[Insert { id: 1 }, Commit])).is_err();
+}
+
+#[test]
+fn one_or_more_requires_at_least_one_item() {
+    let pattern = seq![is(Insert { id: 1 }), one_or_more(like!(Update { .. }))];
+
+    let mismatch = pattern.verify(&[Insert { id: 1 }]).err();
+
+    assert_that!(mismatch.map(|mismatch| mismatch.to_string())).is_equal_to(Some(
+        "output does not match at index 1: expected one or more of Update { .. }, but the output \
+         ended"
+            .to_string(),
+    ));
+}
+
+#[test]
+fn an_element_created_by_like_supports_guards() {
+    let pattern = seq![any_number_of(like!(Update { id } if *id > 2))];
+
+    assert_that!(pattern.verify(&[Update { id: 3 }, Update { id: 4 }])).is_ok();
+    assert_that!(pattern
+        .verify(&[Update { id: 3 }, Update { id: 2 }])
+        .err()
+        .map(|mismatch| mismatch.index()))
+    .is_equal_to(Some(1));
+}
+
+#[test]
+fn an_element_created_by_like_does_not_use_macros_defined_at_the_call_site() {
+    #[allow(unused_macros)]
+    macro_rules! matches {
+        ($($tokens:tt)*) => {
+            false
+        };
+    }
+    #[allow(unused_macros)]
+    macro_rules! stringify {
+        ($($tokens:tt)*) => {
+            "shadowed"
+        };
+    }
+
+    let pattern = seq![like!(Insert { .. })];
+
+    assert_that!(pattern.verify(&[Insert { id: 1 }])).is_ok();
+    assert_that!(pattern
+        .verify(&[Commit])
+        .err()
+        .map(|mismatch| mismatch.to_string()))
+    .is_equal_to(Some(
+        "output does not match at index 0: expected Insert { .. }, but found Commit".to_string(),
+    ));
+}
+
+#[test]
+fn an_element_created_by_satisfies_is_described_by_the_given_description() {
+    let pattern = seq![satisfies("an even number", |number: &i32| number % 2 == 0)];
+
+    let mismatch = pattern.verify(&[3]).err();
+
+    assert_that!(mismatch.map(|mismatch| mismatch.to_string())).is_equal_to(Some(
+        "output does not match at index 0: expected an even number, but found 3".to_string(),
+    ));
+}
+
+#[test]
+fn a_subsequence_matches_elements_with_other_items_in_between() {
+    let pattern = contains_subsequence([like!(Insert { .. }), is(Commit)]);
+
+    assert_that!(pattern.verify(&[
+        Rollback,
+        Insert { id: 1 },
+        Update { id: 1 },
+        Commit,
+        Rollback
+    ]))
+    .is_ok();
+}
+
+#[test]
+fn a_subsequence_reports_the_first_element_that_was_not_found() {
+    let pattern = contains_subsequence([is(Insert { id: 1 }), is(Commit), is(Rollback)]);
+
+    let mismatch = pattern
+        .verify(&[Update { id: 1 }, Insert { id: 1 }, Rollback, Commit])
+        .err();
+
+    assert_that!(mismatch.map(|mismatch| mismatch.to_string())).is_equal_to(Some(
+        "output does not contain the subsequence: expected Rollback at or after index 4, but it \
+         was not found"
+            .to_string(),
+    ));
+}
+
+#[test]
+fn a_subsequence_is_found_at_the_end_of_a_long_output() {
+    let mut output = vec![0; 200_000];
+    output.push(1);
+
+    assert_that!(contains_subsequence([is(1)]).verify(&output)).is_ok();
+    assert_that!(contains_subsequence([is(0), is(1), is(0)])
+        .verify(&output)
+        .err()
+        .map(|mismatch| mismatch.index()))
+    .is_equal_to(Some(200_001));
+}
+
+#[test]
+fn a_sequence_matches_a_long_output() {
+    let mut output = vec![0; 200_000];
+    output.push(1);
+
+    assert_that!(seq![any_number_of(is(0)), is(1)].verify(&output)).is_ok();
+    assert_that!(seq![any_number_of(is(0)), is(2)]
+        .verify(&output)
+        .err()
+        .map(|mismatch| mismatch.index()))
+    .is_equal_to(Some(200_000));
+}
+
+#[test]
+fn never_reports_the_index_of_the_first_forbidden_item() {
+    let pattern = never(is(Rollback));
+
+    assert_that!(pattern.verify(&[Insert { id: 1 }, Commit])).is_ok();
+    assert_that!(pattern
+        .verify(&[Insert { id: 1 }, Rollback, Rollback])
+        .err()
+        .map(|mismatch| mismatch.to_string()))
+    .is_equal_to(Some(
+        "output contains a forbidden item at index 1: expected never Rollback, but found Rollback"
+            .to_string(),
+    ));
+}
+
+proptest! {
+    #[test]
+    fn a_sequence_of_is_elements_matches_only_the_same_output(
+        expected in vec(0..3_u8, 0..8),
+        actual in vec(0..3_u8, 0..8),
+    ) {
+        let pattern = Sequence::new(expected.iter().copied().map(is));
+
+        let result = pattern.verify(&actual);
+
+        prop_assert_eq!(result.is_ok(), expected == actual);
+        if let Err(mismatch) = result {
+            let first_difference = expected
+                .iter()
+                .zip(&actual)
+                .take_while(|(expected, actual)| expected == actual)
+                .count();
+            prop_assert_eq!(mismatch.index(), first_difference);
+        }
+    }
+
+    #[test]
+    fn any_number_of_anything_matches_any_output(
+        output in vec(any::<u8>(), 0..20),
+    ) {
+        prop_assert_eq!(seq![any_number_of(anything())].verify(&output), Ok(()));
+    }
+}
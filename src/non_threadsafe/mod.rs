@@ -2,15 +2,46 @@
 //!
 //! For an example on how to use it see the crate level documentation.
 
-use crate::inner_subject::{BasicSubject, CelledSubject};
-use crate::inner_tracker::{BasicTracker, CelledTracker};
+pub use crate::record::{Record, ThreadOutput};
+pub use crate::tracker_config::{OverflowPolicy, TrackerConfig};
+pub use crate::tracker_snapshot::TrackerSnapshot;
+pub use crate::tracking_scope::TrackingScope;
+pub use crate::unordered_output::{MultisetDifference, UnorderedOutput};
+
+use crate::inner_responses::BasicResponses;
+#[cfg(feature = "stream")]
+use crate::inner_stream::BasicStream;
+use crate::inner_subject::{BasicSubject, CelledSubject, Unsubscribe};
+use crate::inner_tracker::{BasicTracker, CapacityExceeded, CelledTracker};
 use crate::non_threadsafe::Error::{BorrowMutTrackerFailed, BorrowTrackerFailed};
+use crate::record::{group_by_thread, Emission};
 use crate::tracker_handle::TrackerHandle;
+#[cfg(feature = "stream")]
+use futures_core::Stream;
+#[cfg(feature = "serde")]
+use serde::de::DeserializeOwned;
+#[cfg(feature = "serde")]
+use serde::Serialize;
 use std::cell::{BorrowError, BorrowMutError, Ref, RefCell, RefMut};
+use std::fmt::{self, Debug};
+#[cfg(feature = "serde")]
+use std::fs::File;
+use std::hash::Hash;
+#[cfg(feature = "serde")]
+use std::io::{self, BufRead, BufReader, Write};
+#[cfg(feature = "serde")]
+use std::path::Path;
+#[cfg(feature = "stream")]
+use std::pin::Pin;
 use std::rc::Rc;
+#[cfg(feature = "stream")]
+use std::rc::Weak;
+#[cfg(feature = "stream")]
+use std::task::{Context, Poll};
 
 /// Error type for the non-threadsafe [`OutputTracker`] and [`OutputSubject`].
 #[derive(thiserror::Error, Debug)]
+#[non_exhaustive]
 pub enum Error {
     /// Failed to obtain an immutable borrow of the tracker.
     #[error("failed to obtain an immutable borrow of the tracker, reason: {0}")]
@@ -24,6 +55,55 @@ pub enum Error {
     /// Failed to obtain a mutable borrow of the subject.
     #[error("failed to obtain a mutable borrow of the subject, reason: {0}")]
     BorrowMutSubjectFailed(BorrowMutError),
+    /// The capacity of the tracker is exceeded.
+    ///
+    /// This error is only returned for trackers configured with
+    /// [`OverflowPolicy::Error`].
+    #[error("the capacity of the tracker of {0} items is exceeded")]
+    CapacityExceeded(usize),
+    /// Failed to obtain a mutable borrow of the configured responses.
+    #[error("failed to obtain a mutable borrow of the configured responses, reason: {0}")]
+    BorrowMutResponsesFailed(BorrowMutError),
+    /// All responses configured for the named adapter have been consumed.
+    #[error("no more responses configured for {0}")]
+    ResponsesExhausted(String),
+    /// Failed to serialize or export the tracked data.
+    #[cfg(feature = "serde")]
+    #[error("failed to serialize the tracked data, reason: {0}")]
+    SerializationFailed(serde_json::Error),
+    /// Failed to read or deserialize recorded responses.
+    #[cfg(feature = "serde")]
+    #[error("failed to replay the recorded responses, reason: {0}")]
+    ReplayFailed(serde_json::Error),
+    /// Failed to create, open, read or write a recording file, or to write
+    /// the exported data.
+    #[cfg(feature = "serde")]
+    #[error("failed to access the recording file, reason: {0}")]
+    Io(io::Error),
+}
+
+impl From<CapacityExceeded> for Error {
+    fn from(err: CapacityExceeded) -> Self {
+        Self::CapacityExceeded(err.capacity)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<serde_json::Error> for Error {
+    fn from(err: serde_json::Error) -> Self {
+        if err.is_io() {
+            Self::Io(err.into())
+        } else {
+            Self::SerializationFailed(err)
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<io::Error> for Error {
+    fn from(err: io::Error) -> Self {
+        Self::Io(err)
+    }
 }
 
 /// Collects state data or action data of any kind.
@@ -32,30 +112,37 @@ pub enum Error {
 ///
 /// The tracked data can be read any time and as often as needed by calling the
 /// [`output()`][OutputTracker::output]. Each time the output is read, all data
-/// collected so far are returned. To track only new data emitted after the last
-/// read of the output, the [`clear()`][OutputTracker::clear] function should be
-/// called.
+/// collected so far are returned. To read only new data emitted after the last
+/// read of the output, the data can be read and removed at once by calling the
+/// [`drain()`][OutputTracker::drain] function, or it can be read through a
+/// [`Cursor`] obtained from the [`cursor()`][OutputTracker::cursor] function.
 ///
 /// The tracker can be deactivated by calling the [`stop()`][OutputTracker::stop]
 /// function to stop it from collecting data. Once stopped the tracker can not
 /// be activated again.
-#[derive(Debug)]
+///
+/// When an [`OutputTracker`] is dropped, it is stopped automatically. To keep
+/// a tracker registered with its subject beyond the lifetime of the
+/// [`OutputTracker`], it can be turned into a [`DetachedTracker`] by calling
+/// the [`detach()`][OutputTracker::detach] function.
 pub struct OutputTracker<M> {
     handle: TrackerHandle,
     inner: NonThreadsafeTracker<M>,
-    subject: NonThreadsafeSubject<M>,
+    subject: SubjectRef,
+    detached: bool,
 }
 
 impl<M> OutputTracker<M> {
     const fn new(
         handle: TrackerHandle,
         inner: NonThreadsafeTracker<M>,
-        subject: NonThreadsafeSubject<M>,
+        subject: SubjectRef,
     ) -> Self {
         Self {
             handle,
             inner,
             subject,
+            detached: false,
         }
     }
 
@@ -64,25 +151,245 @@ impl<M> OutputTracker<M> {
     /// After stopping a tracker it no longer tracks emitted data. Once a
     /// tracker is stopped it can not be activated again.
     pub fn stop(&self) -> Result<(), Error> {
-        self.subject.remove_tracker(self.handle)
+        self.subject.unsubscribe(self.handle)
+    }
+
+    /// Turns this tracker into a [`DetachedTracker`] that is not stopped when
+    /// it is dropped.
+    ///
+    /// Usually a tracker is stopped when it is dropped. A detached tracker
+    /// stays registered with its subject until it is stopped explicitly by
+    /// calling [`DetachedTracker::stop()`]. The returned handle can be cloned
+    /// to read the tracked data from several places.
+    #[must_use = "a detached tracker can only be read and stopped through the returned handle"]
+    pub fn detach(mut self) -> DetachedTracker<M> {
+        self.detached = true;
+        DetachedTracker {
+            handle: self.handle,
+            inner: self.inner.clone(),
+            subject: self.subject.clone(),
+        }
     }
 
     /// Clears the data this tracker has been collected so far.
     ///
     /// After clearing a tracker it still tracks any data which is emitted after
-    /// this clear function has been called.
+    /// this clear function has been called. The number of dropped items is
+    /// reset as well.
     pub fn clear(&self) -> Result<(), Error> {
         self.inner.clear()
     }
 
+    /// Returns the number of items this tracker has discarded because its
+    /// capacity was exceeded.
+    ///
+    /// Only trackers created with a limited capacity via
+    /// [`create_tracker_with()`][OutputSubject::create_tracker_with] ever
+    /// discard items.
+    pub fn dropped_count(&self) -> Result<usize, Error> {
+        self.inner.dropped_count()
+    }
+
     /// Returns the data collected by this tracker so far.
     ///
     /// Each time this function is called it returns all data collected since
     /// the tracker has been created or since the last call to of the
-    /// [`clear()`][OutputTracker::clear] function. To track only data that are
-    /// emitted after the last time the output was read, the
-    /// [`clear()`][OutputTracker::clear] should be called after the output has
-    /// been read.
+    /// [`clear()`][OutputTracker::clear] function. To read only data that are
+    /// emitted after the last time the output was read, use the
+    /// [`drain()`][OutputTracker::drain] function or a [`Cursor`] instead.
+    pub fn output(&self) -> Result<Vec<M>, Error>
+    where
+        M: Clone,
+    {
+        self.inner.output()
+    }
+
+    /// Returns the data collected by this tracker so far that has been emitted
+    /// within a [`TrackingScope`] with the given correlation id.
+    ///
+    /// The data is returned in the order it has been tracked.
+    pub fn output_for(&self, correlation_id: &str) -> Result<Vec<M>, Error>
+    where
+        M: Clone,
+    {
+        self.inner.output_for(correlation_id)
+    }
+
+    /// Returns the data collected by this tracker so far for comparing it
+    /// without regard to the order in which it has been tracked.
+    ///
+    /// The items are compared using their implementation of `Eq` and `Hash`.
+    /// For items that implement `Ord` but not `Hash`, use
+    /// [`output_unordered_by_ord()`][OutputTracker::output_unordered_by_ord]
+    /// instead.
+    pub fn output_unordered(&self) -> Result<UnorderedOutput<M>, Error>
+    where
+        M: Eq + Hash + Clone,
+    {
+        self.inner.output().map(UnorderedOutput::hashed)
+    }
+
+    /// Returns the data collected by this tracker so far for comparing it
+    /// without regard to the order in which it has been tracked.
+    ///
+    /// The items are compared using their implementation of `Ord`. This is
+    /// the counterpart of [`output_unordered()`][OutputTracker::output_unordered]
+    /// for items that do not implement `Hash`.
+    pub fn output_unordered_by_ord(&self) -> Result<UnorderedOutput<M>, Error>
+    where
+        M: Ord + Clone,
+    {
+        self.inner.output().map(UnorderedOutput::ordered)
+    }
+
+    /// Returns a snapshot of the state of this tracker.
+    ///
+    /// The snapshot holds the data collected so far together with the number
+    /// of discarded items, both read at the same time.
+    pub fn snapshot(&self) -> Result<TrackerSnapshot<M>, Error>
+    where
+        M: Clone,
+    {
+        self.inner.snapshot()
+    }
+
+    /// Returns the data collected by this tracker so far serialized as JSON
+    /// array.
+    #[cfg(feature = "serde")]
+    pub fn output_json(&self) -> Result<String, Error>
+    where
+        M: Serialize,
+    {
+        self.inner.output_json()
+    }
+
+    /// Writes the data collected by this tracker so far to the given writer
+    /// in the JSON Lines format.
+    ///
+    /// Each item is serialized as JSON and written on a line of its own.
+    #[cfg(feature = "serde")]
+    pub fn export_jsonl(&self, writer: impl Write) -> Result<(), Error>
+    where
+        M: Serialize,
+    {
+        self.inner.export_jsonl(writer)
+    }
+
+    /// Saves the data collected by this tracker so far to the file at the
+    /// given path in the JSON Lines format.
+    ///
+    /// The file is created or truncated if it exists already. A saved
+    /// recording can be replayed by a nulled infrastructure wrapper using
+    /// [`ConfigurableResponses::load_recording()`].
+    #[cfg(feature = "serde")]
+    pub fn save_recording(&self, path: impl AsRef<Path>) -> Result<(), Error>
+    where
+        M: Serialize,
+    {
+        self.inner.save_recording(path.as_ref())
+    }
+
+    /// Returns the data collected by this tracker so far and removes it from
+    /// this tracker.
+    ///
+    /// Reading and removing the data happens in one step. In contrast to
+    /// calling [`output()`][OutputTracker::output] followed by
+    /// [`clear()`][OutputTracker::clear], no data emitted in between the two
+    /// calls gets lost, and the data is not cloned.
+    pub fn drain(&self) -> Result<Vec<M>, Error> {
+        self.inner.drain()
+    }
+
+    /// Returns a [`Cursor`] for reading the data collected by this tracker
+    /// incrementally.
+    ///
+    /// Reading through a cursor does not remove any data from this tracker.
+    #[must_use]
+    pub fn cursor(&self) -> Cursor<M> {
+        Cursor {
+            tracker: self.inner.clone(),
+            position: 0,
+        }
+    }
+}
+
+impl<M> OutputTracker<Record<M>> {
+    /// Returns the records collected by this tracker so far grouped by the
+    /// thread that emitted the data.
+    ///
+    /// As the non-threadsafe variant can only be used within one thread, the
+    /// returned list holds at most one group.
+    pub fn output_by_thread(&self) -> Result<Vec<ThreadOutput<M>>, Error>
+    where
+        M: Clone,
+    {
+        self.inner.output().map(group_by_thread)
+    }
+}
+
+impl<M> Drop for OutputTracker<M> {
+    fn drop(&mut self) {
+        if !self.detached {
+            // errors can not be reported from `drop`, the tracker is removed
+            // on a best effort basis
+            _ = self.subject.unsubscribe(self.handle);
+        }
+    }
+}
+
+impl<M> Debug for OutputTracker<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutputTracker")
+            .field("handle", &self.handle)
+            .field("inner", &self.inner)
+            .field("detached", &self.detached)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A handle to a tracker that stays registered with its subject until it is
+/// stopped explicitly.
+///
+/// This is the non-threadsafe variant.
+///
+/// A detached tracker is obtained by calling
+/// [`detach()`][OutputTracker::detach] on an [`OutputTracker`]. Other than an
+/// [`OutputTracker`], dropping a detached tracker does not stop it. All clones
+/// of a detached tracker read the same tracked data.
+pub struct DetachedTracker<M> {
+    handle: TrackerHandle,
+    inner: NonThreadsafeTracker<M>,
+    subject: SubjectRef,
+}
+
+impl<M> Clone for DetachedTracker<M> {
+    fn clone(&self) -> Self {
+        Self {
+            handle: self.handle,
+            inner: self.inner.clone(),
+            subject: self.subject.clone(),
+        }
+    }
+}
+
+impl<M> DetachedTracker<M> {
+    /// Stops this tracker.
+    ///
+    /// After stopping a tracker it no longer tracks emitted data. Once a
+    /// tracker is stopped it can not be activated again.
+    pub fn stop(&self) -> Result<(), Error> {
+        self.subject.unsubscribe(self.handle)
+    }
+
+    /// Clears the data this tracker has been collected so far.
+    pub fn clear(&self) -> Result<(), Error> {
+        self.inner.clear()
+    }
+
+    /// Returns the data collected by this tracker so far.
     pub fn output(&self) -> Result<Vec<M>, Error>
     where
         M: Clone,
@@ -91,6 +398,377 @@ impl<M> OutputTracker<M> {
     }
 }
 
+impl<M> Debug for DetachedTracker<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("DetachedTracker")
+            .field("handle", &self.handle)
+            .field("inner", &self.inner)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Reads the data collected by an [`OutputTracker`] incrementally.
+///
+/// This is the non-threadsafe variant.
+///
+/// A cursor is obtained by calling [`cursor()`][OutputTracker::cursor] on an
+/// [`OutputTracker`]. Each call to [`take_new()`][Cursor::take_new] returns
+/// only the data that has been collected since the previous call. Several
+/// cursors can read the same tracker independently of each other.
+#[derive(Debug)]
+pub struct Cursor<M> {
+    tracker: NonThreadsafeTracker<M>,
+    position: usize,
+}
+
+impl<M> Cursor<M> {
+    /// Returns the data collected by the tracker since the last call of this
+    /// function.
+    ///
+    /// The first call returns all data the tracker holds at that time. Data
+    /// that has been cleared or drained from the tracker before this cursor
+    /// has read it, is skipped.
+    pub fn take_new(&mut self) -> Result<Vec<M>, Error>
+    where
+        M: Clone,
+    {
+        let (new_data, end_position) = self.tracker.output_from(self.position)?;
+        self.position = end_position;
+        Ok(new_data)
+    }
+}
+
+/// Yields the data emitted by an [`OutputSubject`] as it arrives.
+///
+/// This is the non-threadsafe variant.
+///
+/// A stream is obtained by calling
+/// [`subscribe_stream()`][OutputSubject::subscribe_stream] on an
+/// [`OutputSubject`]. It yields every item emitted after it has been created
+/// in the order the items are emitted. The stream ends when the subject and
+/// all of its clones have been dropped.
+///
+/// When an [`OutputStream`] is dropped, it is unsubscribed from its subject
+/// automatically.
+#[cfg(feature = "stream")]
+#[must_use = "streams do nothing unless polled"]
+pub struct OutputStream<M> {
+    handle: TrackerHandle,
+    state: Rc<RefCell<BasicStream<M>>>,
+    subject: SubjectRef,
+}
+
+#[cfg(feature = "stream")]
+impl<M> Stream for OutputStream<M> {
+    type Item = M;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<M>> {
+        // a stream that can not access its state has no more data to yield
+        self.state
+            .try_borrow_mut()
+            .map_or(Poll::Ready(None), |mut state| state.poll_next(cx))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.state
+            .try_borrow()
+            .map_or((0, None), |state| state.size_hint())
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<M> Drop for OutputStream<M> {
+    fn drop(&mut self) {
+        // errors can not be reported from `drop`, the stream is removed on a
+        // best effort basis
+        _ = self.subject.unsubscribe(self.handle);
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<M> Debug for OutputStream<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("OutputStream")
+            .field("handle", &self.handle)
+            .field("state", &self.state)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Records the data emitted by several [`OutputSubject`]s in one globally
+/// ordered list.
+///
+/// This is the non-threadsafe variant.
+///
+/// A timeline is the counterpart of an [`OutputTracker`] spanning several
+/// subjects. The subjects may emit data of different types. When attaching a
+/// subject via [`attach()`][Timeline::attach], a function is given that maps
+/// the data emitted by this subject into the common type of entries recorded
+/// by the timeline, e.g. an enum with one variant per subject.
+///
+/// The entries are recorded in the order the data is emitted, regardless of
+/// the subject that emitted it. When a [`Timeline`] is dropped, it is
+/// detached from all subjects automatically.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::{OutputSubject, Timeline};
+///
+/// #[derive(Debug, Clone, PartialEq)]
+/// enum Event {
+///     Inserted(u64),
+///     EmailSent(String),
+/// }
+///
+/// let repository = OutputSubject::<u64>::new();
+/// let mailer = OutputSubject::<String>::new();
+///
+/// let mut timeline = Timeline::new();
+/// timeline.attach(&repository, |id| Event::Inserted(*id)).unwrap();
+/// timeline.attach(&mailer, |email| Event::EmailSent(email.clone())).unwrap();
+///
+/// repository.emit(42).unwrap();
+/// mailer.emit("welcome".into()).unwrap();
+///
+/// assert_eq!(
+///     timeline.output().unwrap(),
+///     vec![Event::Inserted(42), Event::EmailSent("welcome".into())]
+/// );
+/// ```
+#[derive(Debug)]
+pub struct Timeline<T> {
+    inner: NonThreadsafeTracker<T>,
+    attachments: Vec<OutputTracker<T>>,
+}
+
+impl<T> Default for Timeline<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> Timeline<T> {
+    /// Constructs a new [`Timeline`] that is not attached to any subject yet.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            inner: NonThreadsafeTracker::new(TrackerConfig::default()),
+            attachments: Vec::new(),
+        }
+    }
+
+    /// Attaches this timeline to the given subject.
+    ///
+    /// Each item emitted by the subject after attaching is mapped by the given
+    /// function and recorded as an entry of this timeline.
+    pub fn attach<M>(
+        &mut self,
+        subject: &OutputSubject<M>,
+        mapping: impl Fn(&M) -> T + 'static,
+    ) -> Result<(), Error>
+    where
+        M: 'static,
+        T: 'static,
+    {
+        let attachment =
+            subject.attach_tracker(self.inner.clone(), move |data, _| Some(mapping(data)))?;
+        self.attachments.push(attachment);
+        Ok(())
+    }
+
+    /// Stops this timeline.
+    ///
+    /// After stopping a timeline it no longer records data emitted by any of
+    /// the attached subjects.
+    pub fn stop(&self) -> Result<(), Error> {
+        self.attachments.iter().try_for_each(OutputTracker::stop)
+    }
+
+    /// Clears the entries this timeline has recorded so far.
+    pub fn clear(&self) -> Result<(), Error> {
+        self.inner.clear()
+    }
+
+    /// Returns the entries recorded by this timeline so far in the order the
+    /// data has been emitted.
+    pub fn output(&self) -> Result<Vec<T>, Error>
+    where
+        T: Clone,
+    {
+        self.inner.output()
+    }
+
+    /// Returns the entries recorded by this timeline so far and removes them
+    /// from this timeline.
+    pub fn drain(&self) -> Result<Vec<T>, Error> {
+        self.inner.drain()
+    }
+
+    /// Returns a [`Cursor`] for reading the entries recorded by this timeline
+    /// incrementally.
+    #[must_use]
+    pub fn cursor(&self) -> Cursor<T> {
+        Cursor {
+            tracker: self.inner.clone(),
+            position: 0,
+        }
+    }
+}
+
+/// Responses configured for a nulled infrastructure wrapper.
+///
+/// This is the non-threadsafe variant.
+///
+/// Nulled implementations of infrastructure wrappers return configured
+/// responses instead of talking to the outside world. A
+/// [`ConfigurableResponses`] either returns the same response every time
+/// [`next()`][ConfigurableResponses::next] is called or returns the responses
+/// of a finite list one after another. Once all responses of the list have
+/// been returned, [`next()`][ConfigurableResponses::next] fails with an error
+/// naming the adapter the responses have been configured for.
+///
+/// Clones of a [`ConfigurableResponses`] share the same responses.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::ConfigurableResponses;
+///
+/// let responses = ConfigurableResponses::sequence("NulledDb", [Ok(1), Err("duplicate key")]);
+///
+/// assert_eq!(responses.next().unwrap(), Ok(1));
+/// assert_eq!(responses.next().unwrap(), Err("duplicate key"));
+/// assert_eq!(
+///     responses.next().unwrap_err().to_string(),
+///     "no more responses configured for NulledDb"
+/// );
+/// ```
+#[derive(Debug)]
+pub struct ConfigurableResponses<T> {
+    cell: Rc<RefCell<BasicResponses<T>>>,
+}
+
+impl<T> Clone for ConfigurableResponses<T> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: Rc::clone(&self.cell),
+        }
+    }
+}
+
+impl<T> ConfigurableResponses<T> {
+    /// Constructs a [`ConfigurableResponses`] that returns the given response
+    /// every time.
+    ///
+    /// The name of the adapter is used in error messages.
+    pub fn always(adapter: impl Into<String>, response: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            cell: Rc::new(RefCell::new(BasicResponses::always(
+                adapter.into(),
+                response,
+            ))),
+        }
+    }
+
+    /// Constructs a [`ConfigurableResponses`] that returns the given responses
+    /// one after another.
+    ///
+    /// The name of the adapter is used in the error message returned when all
+    /// responses have been consumed.
+    pub fn sequence(adapter: impl Into<String>, responses: impl IntoIterator<Item = T>) -> Self {
+        Self {
+            cell: Rc::new(RefCell::new(BasicResponses::sequence(
+                adapter.into(),
+                responses,
+            ))),
+        }
+    }
+
+    /// Constructs a [`ConfigurableResponses`] that replays the responses
+    /// recorded in the given reader one after another.
+    ///
+    /// The recording is expected in the JSON Lines format as written by
+    /// [`OutputTracker::export_jsonl()`]. Each non-empty line is deserialized
+    /// into one response.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use output_tracker::non_threadsafe::{ConfigurableResponses, OutputSubject};
+    ///
+    /// // record the responses of the real adapter
+    /// let subject = OutputSubject::new();
+    /// let tracker = subject.create_tracker().unwrap();
+    /// subject.emit(vec!["alice".to_string()]).unwrap();
+    /// subject.emit(vec!["alice".to_string(), "bob".to_string()]).unwrap();
+    ///
+    /// let mut recording = Vec::new();
+    /// tracker.export_jsonl(&mut recording).unwrap();
+    ///
+    /// // replay the recorded responses in the nulled adapter
+    /// let responses =
+    ///     ConfigurableResponses::<Vec<String>>::replay("NulledUsers", &recording[..]).unwrap();
+    ///
+    /// assert_eq!(responses.next().unwrap(), vec!["alice"]);
+    /// assert_eq!(responses.next().unwrap(), vec!["alice", "bob"]);
+    /// assert!(responses.next().is_err());
+    /// ```
+    #[cfg(feature = "serde")]
+    pub fn replay(adapter: impl Into<String>, recording: impl BufRead) -> Result<Self, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let responses = BasicResponses::replay(adapter.into(), recording).map_err(|err| {
+            if err.is_io() {
+                Error::Io(err.into())
+            } else {
+                Error::ReplayFailed(err)
+            }
+        })?;
+        Ok(Self {
+            cell: Rc::new(RefCell::new(responses)),
+        })
+    }
+
+    /// Constructs a [`ConfigurableResponses`] that replays the responses
+    /// recorded in the file at the given path one after another.
+    ///
+    /// The file is expected in the JSON Lines format as written by
+    /// [`OutputTracker::save_recording()`].
+    #[cfg(feature = "serde")]
+    pub fn load_recording(adapter: impl Into<String>, path: impl AsRef<Path>) -> Result<Self, Error>
+    where
+        T: DeserializeOwned,
+    {
+        let file = File::open(path).map_err(Error::Io)?;
+        Self::replay(adapter, BufReader::new(file))
+    }
+
+    /// Returns the next configured response.
+    ///
+    /// Fails with [`Error::ResponsesExhausted`] if all configured responses
+    /// have been consumed already.
+    pub fn next(&self) -> Result<T, Error> {
+        let mut responses = self
+            .cell
+            .try_borrow_mut()
+            .map_err(Error::BorrowMutResponsesFailed)?;
+        responses
+            .next()
+            .ok_or_else(|| Error::ResponsesExhausted(responses.adapter().to_owned()))
+    }
+}
+
 /// Holds created [`OutputTracker`]s and emits data to all known trackers.
 ///
 /// This is the non-threadsafe variant.
@@ -100,11 +778,25 @@ impl<M> OutputTracker<M> {
 ///
 /// The [`emit(data)`][OutputSubject::emit] function emits data to all trackers,
 /// that have been created for this subject and are not stopped yet.
-#[derive(Default, Debug, Clone)]
+#[derive(Debug)]
 pub struct OutputSubject<M> {
     inner: NonThreadsafeSubject<M>,
 }
 
+impl<M> Default for OutputSubject<M> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<M> Clone for OutputSubject<M> {
+    fn clone(&self) -> Self {
+        Self {
+            inner: self.inner.clone(),
+        }
+    }
+}
+
 impl<M> OutputSubject<M> {
     /// Constructs a new [`OutputSubject`].
     ///
@@ -116,31 +808,157 @@ impl<M> OutputSubject<M> {
             inner: NonThreadsafeSubject::new(),
         }
     }
+
+    /// Emits given data to all active [`OutputTracker`]s.
+    ///
+    /// Stopped [`OutputTracker`]s do not receive any emitted data.
+    ///
+    /// If a tracker fails to record the data, the data is still emitted to
+    /// all other trackers and the first error that occurred is returned.
+    pub fn emit(&self, data: M) -> Result<(), Error> {
+        self.inner.emit(data)
+    }
 }
 
 impl<M> OutputSubject<M>
 where
-    M: Clone,
+    M: Clone + 'static,
 {
     /// Creates a new [`OutputTracker`] and registers it to be ready to track
     /// emitted data.
     pub fn create_tracker(&self) -> Result<OutputTracker<M>, Error> {
-        let new_tracker = NonThreadsafeTracker::new();
-        let handle = self.inner.add_tracker(new_tracker.clone())?;
-        Ok(OutputTracker::new(handle, new_tracker, self.inner.clone()))
+        self.create_tracker_with(TrackerConfig::default())
     }
 
-    /// Emits given data to all active [`OutputTracker`]s.
+    /// Creates a new [`OutputTracker`] with the given configuration and
+    /// registers it to be ready to track emitted data.
     ///
-    /// Stopped [`OutputTracker`]s do not receive any emitted data.
-    pub fn emit(&self, data: M) -> Result<(), Error> {
-        self.inner.emit(data)
+    /// See [`TrackerConfig`] for the available options.
+    pub fn create_tracker_with(&self, config: TrackerConfig) -> Result<OutputTracker<M>, Error> {
+        self.register_tracker(config, |data| Some(data.clone()))
+    }
+
+    /// Creates a new [`OutputTracker`] that tracks only emitted data matching
+    /// the given predicate and registers it to be ready to track emitted data.
+    ///
+    /// Emitted data that does not match the predicate is neither recorded nor
+    /// cloned for this tracker.
+    pub fn create_filtered_tracker(
+        &self,
+        predicate: impl Fn(&M) -> bool + 'static,
+    ) -> Result<OutputTracker<M>, Error> {
+        self.register_tracker(TrackerConfig::default(), move |data| {
+            predicate(data).then(|| data.clone())
+        })
+    }
+
+    /// Creates a new [`OutputTracker`] that tracks each emitted item as a
+    /// [`Record`] and registers it to be ready to track emitted data.
+    ///
+    /// Besides the data, each record holds a sequence number, the moment the
+    /// data has been emitted and the thread that emitted it. The sequence
+    /// numbers of records are ordered across all subjects, which allows to
+    /// assert the order of data emitted by different subjects. All recording
+    /// trackers of this subject record one emission with the same sequence
+    /// number.
+    pub fn create_recording_tracker(&self) -> Result<OutputTracker<Record<M>>, Error> {
+        self.attach_tracker(
+            NonThreadsafeTracker::new(TrackerConfig::default()),
+            |data, emission| Some(emission.record(data.clone())),
+        )
+    }
+
+    /// Creates a new [`OutputStream`] that yields all data emitted after it
+    /// has been created.
+    ///
+    /// The stream ends when this subject and all of its clones have been
+    /// dropped.
+    #[cfg(feature = "stream")]
+    pub fn subscribe_stream(&self) -> Result<OutputStream<M>, Error> {
+        let state = Rc::new(RefCell::new(BasicStream::new()));
+        let sender = StreamSender {
+            state: Rc::clone(&state),
+        };
+        let handle = self
+            .inner
+            .add_tracker(Box::new(move |data, _| sender.send(data.clone())))?;
+        Ok(OutputStream {
+            handle,
+            state,
+            subject: Rc::new(self.inner.downgrade()),
+        })
     }
 }
 
-#[derive(Default, Debug, Clone)]
+impl<M> OutputSubject<M>
+where
+    M: 'static,
+{
+    /// Creates a new [`OutputTracker`] that tracks a projection of the emitted
+    /// data and registers it to be ready to track emitted data.
+    ///
+    /// The given function maps each emitted item to the value that is
+    /// recorded by the tracker. The emitted data itself is not cloned, so
+    /// the data type of the subject does not need to implement [`Clone`] for
+    /// creating mapped trackers.
+    pub fn create_mapped_tracker<N>(
+        &self,
+        projection: impl Fn(&M) -> N + 'static,
+    ) -> Result<OutputTracker<N>, Error>
+    where
+        N: 'static,
+    {
+        self.register_tracker(TrackerConfig::default(), move |data| Some(projection(data)))
+    }
+
+    fn register_tracker<N>(
+        &self,
+        config: TrackerConfig,
+        select: impl Fn(&M) -> Option<N> + 'static,
+    ) -> Result<OutputTracker<N>, Error>
+    where
+        N: 'static,
+    {
+        self.attach_tracker(NonThreadsafeTracker::new(config), move |data, _| {
+            select(data)
+        })
+    }
+
+    fn attach_tracker<N>(
+        &self,
+        tracker: NonThreadsafeTracker<N>,
+        select: impl Fn(&M, &Emission<'_>) -> Option<N> + 'static,
+    ) -> Result<OutputTracker<N>, Error>
+    where
+        N: 'static,
+    {
+        let slot_tracker = tracker.clone();
+        let handle = self.inner.add_tracker(Box::new(move |data, emission| {
+            select(data, emission).map_or(Ok(()), |selected| slot_tracker.track(selected))
+        }))?;
+        Ok(OutputTracker::new(
+            handle,
+            tracker,
+            Rc::new(self.inner.clone()),
+        ))
+    }
+}
+
+type TrackerSlot<M> = Box<dyn Fn(&M, &Emission<'_>) -> Result<(), Error>>;
+
+type SubjectRef = Rc<dyn Unsubscribe<Error = Error>>;
+
+#[derive(Debug)]
 struct NonThreadsafeSubject<M> {
-    cell: Rc<RefCell<BasicSubject<M, NonThreadsafeTracker<M>>>>,
+    cell: Rc<RefCell<BasicSubject<M, TrackerSlot<M>>>>,
+}
+
+impl<M> Clone for NonThreadsafeSubject<M> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: Rc::clone(&self.cell),
+        }
+    }
 }
 
 impl<M> NonThreadsafeSubject<M> {
@@ -149,15 +967,22 @@ impl<M> NonThreadsafeSubject<M> {
             cell: Rc::new(RefCell::new(BasicSubject::new())),
         }
     }
+
+    #[cfg(feature = "stream")]
+    fn downgrade(&self) -> WeakNonThreadsafeSubject<M> {
+        WeakNonThreadsafeSubject {
+            cell: Rc::downgrade(&self.cell),
+        }
+    }
 }
 
-impl<M> CelledSubject<M, NonThreadsafeTracker<M>> for NonThreadsafeSubject<M> {
+impl<M> CelledSubject<M, TrackerSlot<M>> for NonThreadsafeSubject<M> {
     type Inner<'a>
-        = Ref<'a, BasicSubject<M, NonThreadsafeTracker<M>>>
+        = Ref<'a, BasicSubject<M, TrackerSlot<M>>>
     where
         M: 'a;
     type InnerMut<'a>
-        = RefMut<'a, BasicSubject<M, NonThreadsafeTracker<M>>>
+        = RefMut<'a, BasicSubject<M, TrackerSlot<M>>>
     where
         M: 'a;
     type Error = Error;
@@ -173,11 +998,70 @@ impl<M> CelledSubject<M, NonThreadsafeTracker<M>> for NonThreadsafeSubject<M> {
     }
 }
 
-#[derive(Debug, Clone)]
+impl<M> Unsubscribe for NonThreadsafeSubject<M> {
+    type Error = Error;
+
+    fn unsubscribe(&self, tracker: TrackerHandle) -> Result<(), Error> {
+        self.remove_tracker(tracker)
+    }
+}
+
+// does not keep the subject alive, so that streams end when the subject is
+// dropped
+#[cfg(feature = "stream")]
+struct WeakNonThreadsafeSubject<M> {
+    cell: Weak<RefCell<BasicSubject<M, TrackerSlot<M>>>>,
+}
+
+#[cfg(feature = "stream")]
+impl<M> Unsubscribe for WeakNonThreadsafeSubject<M> {
+    type Error = Error;
+
+    fn unsubscribe(&self, tracker: TrackerHandle) -> Result<(), Error> {
+        self.cell.upgrade().map_or(Ok(()), |cell| {
+            NonThreadsafeSubject { cell }.remove_tracker(tracker)
+        })
+    }
+}
+
+#[cfg(feature = "stream")]
+struct StreamSender<M> {
+    state: Rc<RefCell<BasicStream<M>>>,
+}
+
+#[cfg(feature = "stream")]
+impl<M> StreamSender<M> {
+    fn send(&self, data: M) -> Result<(), Error> {
+        self.state
+            .try_borrow_mut()
+            .map(|mut state| state.push(data))
+            .map_err(BorrowMutTrackerFailed)
+    }
+}
+
+#[cfg(feature = "stream")]
+impl<M> Drop for StreamSender<M> {
+    fn drop(&mut self) {
+        // ends the stream when it is unsubscribed or the subject is dropped
+        if let Ok(mut state) = self.state.try_borrow_mut() {
+            state.close();
+        }
+    }
+}
+
+#[derive(Debug)]
 struct NonThreadsafeTracker<M> {
     cell: Rc<RefCell<BasicTracker<M>>>,
 }
 
+impl<M> Clone for NonThreadsafeTracker<M> {
+    fn clone(&self) -> Self {
+        Self {
+            cell: Rc::clone(&self.cell),
+        }
+    }
+}
+
 impl<M> CelledTracker<M> for NonThreadsafeTracker<M> {
     type Inner<'a>
         = Ref<'a, BasicTracker<M>>
@@ -189,9 +1073,9 @@ impl<M> CelledTracker<M> for NonThreadsafeTracker<M> {
         Self: 'a;
     type Error = Error;
 
-    fn new() -> Self {
+    fn new(config: TrackerConfig) -> Self {
         Self {
-            cell: Rc::new(RefCell::new(BasicTracker::new())),
+            cell: Rc::new(RefCell::new(BasicTracker::new(config))),
         }
     }
 
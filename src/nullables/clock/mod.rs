@@ -0,0 +1,139 @@
+use crate::threadsafe::{Error, OutputSubject, OutputTracker};
+use std::sync::{Arc, Mutex, PoisonError};
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// An action performed on a [`Clock`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ClockAction {
+    /// The current time has been read.
+    Now,
+    /// The clock has been asked to sleep for the given duration.
+    Sleep(Duration),
+}
+
+/// A nullable source of time.
+///
+/// A clock created by [`Clock::new()`] reads the system's monotonic clock and
+/// sleeps by blocking the current thread. A clock created by
+/// [`Clock::nulled()`] starts at the moment it has been created and only
+/// advances when [`advance()`][Clock::advance] or [`sleep()`][Clock::sleep] is
+/// called. Sleeping on a nulled clock returns immediately.
+///
+/// Clones of a clock share the same time and emit their actions to the same
+/// trackers.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::nullables::{Clock, ClockAction};
+/// use std::time::Duration;
+///
+/// let clock = Clock::nulled();
+/// let tracker = clock.track_actions().unwrap();
+/// let start = clock.now();
+///
+/// for _ in 0..3 {
+///     clock.sleep(Duration::from_millis(200));
+/// }
+///
+/// assert_eq!(clock.now() - start, Duration::from_millis(600));
+/// assert_eq!(
+///     tracker.output().unwrap(),
+///     vec![
+///         ClockAction::Now,
+///         ClockAction::Sleep(Duration::from_millis(200)),
+///         ClockAction::Sleep(Duration::from_millis(200)),
+///         ClockAction::Sleep(Duration::from_millis(200)),
+///         ClockAction::Now,
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Clock {
+    time: TimeSource,
+    subject: OutputSubject<ClockAction>,
+}
+
+#[derive(Debug, Clone)]
+enum TimeSource {
+    Real,
+    Nulled {
+        start: Instant,
+        elapsed: Arc<Mutex<Duration>>,
+    },
+}
+
+impl Default for Clock {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Clock {
+    /// Constructs a clock that reads the system's monotonic clock.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            time: TimeSource::Real,
+            subject: OutputSubject::new(),
+        }
+    }
+
+    /// Constructs a nulled clock whose time is advanced manually.
+    #[must_use]
+    pub fn nulled() -> Self {
+        Self {
+            time: TimeSource::Nulled {
+                start: Instant::now(),
+                elapsed: Arc::new(Mutex::new(Duration::ZERO)),
+            },
+            subject: OutputSubject::new(),
+        }
+    }
+
+    /// Creates an [`OutputTracker`] that tracks the actions performed on this
+    /// clock.
+    pub fn track_actions(&self) -> Result<OutputTracker<ClockAction>, Error> {
+        self.subject.create_tracker()
+    }
+
+    /// Returns the current time of this clock.
+    pub fn now(&self) -> Instant {
+        let now = match &self.time {
+            TimeSource::Real => Instant::now(),
+            TimeSource::Nulled { start, elapsed } => {
+                *start + *elapsed.lock().unwrap_or_else(PoisonError::into_inner)
+            },
+        };
+        _ = self.subject.emit(ClockAction::Now);
+        now
+    }
+
+    /// Sleeps for the given duration.
+    ///
+    /// A nulled clock does not block but advances its time by the given
+    /// duration.
+    pub fn sleep(&self, duration: Duration) {
+        match &self.time {
+            TimeSource::Real => thread::sleep(duration),
+            TimeSource::Nulled { elapsed, .. } => {
+                *elapsed.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+            },
+        }
+        _ = self.subject.emit(ClockAction::Sleep(duration));
+    }
+
+    /// Advances the time of a nulled clock by the given duration.
+    ///
+    /// Advancing the time is not tracked as an action. It has no effect on a
+    /// clock that reads the system's clock.
+    pub fn advance(&self, duration: Duration) {
+        if let TimeSource::Nulled { elapsed, .. } = &self.time {
+            *elapsed.lock().unwrap_or_else(PoisonError::into_inner) += duration;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,76 @@
+use super::*;
+use asserting::prelude::*;
+
+#[test]
+fn the_time_of_a_nulled_clock_does_not_advance_by_itself() {
+    let clock = Clock::nulled();
+
+    let start = clock.now();
+    thread::sleep(Duration::from_millis(5));
+
+    assert_that!(clock.now()).is_equal_to(start);
+}
+
+#[test]
+fn the_time_of_a_nulled_clock_is_advanced_manually() {
+    let clock = Clock::nulled();
+    let start = clock.now();
+
+    clock.advance(Duration::from_secs(3));
+
+    assert_that!(clock.now() - start).is_equal_to(Duration::from_secs(3));
+}
+
+#[test]
+fn sleeping_on_a_nulled_clock_advances_its_time_without_blocking() {
+    let clock = Clock::nulled();
+    let start = clock.now();
+    let real_start = Instant::now();
+
+    clock.sleep(Duration::from_secs(60));
+
+    assert_that!(clock.now() - start).is_equal_to(Duration::from_secs(60));
+    assert_that!(real_start.elapsed()).is_less_than(Duration::from_secs(1));
+}
+
+#[test]
+fn clones_of_a_nulled_clock_share_the_same_time() {
+    let clock = Clock::nulled();
+    let cloned_clock = clock.clone();
+    let start = clock.now();
+
+    cloned_clock.advance(Duration::from_millis(500));
+
+    assert_that!(clock.now() - start).is_equal_to(Duration::from_millis(500));
+}
+
+#[test]
+fn sleeping_on_a_real_clock_blocks_for_the_given_duration() {
+    let clock = Clock::new();
+    let start = clock.now();
+
+    clock.sleep(Duration::from_millis(10));
+
+    assert_that!(clock.now() - start).is_at_least(Duration::from_millis(10));
+}
+
+#[test]
+fn the_actions_performed_on_a_clock_are_tracked() {
+    let clock = Clock::nulled();
+    let tracker = clock
+        .track_actions()
+        .unwrap_or_else(|err| panic!("could not create clock tracker: {err}"));
+
+    _ = clock.now();
+    for _ in 0..3 {
+        clock.sleep(Duration::from_millis(200));
+    }
+    clock.advance(Duration::from_secs(1));
+
+    assert_that!(tracker.output()).ok().contains_exactly([
+        ClockAction::Now,
+        ClockAction::Sleep(Duration::from_millis(200)),
+        ClockAction::Sleep(Duration::from_millis(200)),
+        ClockAction::Sleep(Duration::from_millis(200)),
+    ]);
+}
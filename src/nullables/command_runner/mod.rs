@@ -0,0 +1,281 @@
+use crate::threadsafe::{ConfigurableResponses, Error, OutputSubject, OutputTracker};
+use std::collections::HashMap;
+use std::io;
+use std::path::PathBuf;
+use std::process::Command;
+use std::sync::Arc;
+
+/// The invocation of a command by a [`CommandRunner`].
+///
+/// An invocation is built from the program to run and optionally arguments,
+/// environment variables and a working directory.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::nullables::CommandInvocation;
+///
+/// let invocation = CommandInvocation::new("git")
+///     .arg("commit")
+///     .args(["-m", "initial commit"])
+///     .env("GIT_AUTHOR_NAME", "Alice")
+///     .current_dir("/tmp/repo");
+///
+/// assert_eq!(invocation.command_line(), r#"git commit -m "initial commit""#);
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CommandInvocation {
+    /// The program to run.
+    pub program: String,
+    /// The arguments passed to the program.
+    pub args: Vec<String>,
+    /// The environment variables set for the program in addition to the
+    /// inherited ones.
+    pub env: Vec<(String, String)>,
+    /// The working directory of the program, if it differs from the one of
+    /// the current process.
+    pub current_dir: Option<PathBuf>,
+}
+
+impl CommandInvocation {
+    /// Constructs an invocation of the given program without any arguments.
+    pub fn new(program: impl Into<String>) -> Self {
+        Self {
+            program: program.into(),
+            ..Self::default()
+        }
+    }
+
+    /// Adds an argument to this invocation.
+    #[must_use]
+    pub fn arg(mut self, arg: impl Into<String>) -> Self {
+        self.args.push(arg.into());
+        self
+    }
+
+    /// Adds several arguments to this invocation.
+    #[must_use]
+    pub fn args(mut self, args: impl IntoIterator<Item = impl Into<String>>) -> Self {
+        self.args.extend(args.into_iter().map(Into::into));
+        self
+    }
+
+    /// Sets an environment variable for this invocation.
+    #[must_use]
+    pub fn env(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.env.push((key.into(), value.into()));
+        self
+    }
+
+    /// Sets the working directory for this invocation.
+    #[must_use]
+    pub fn current_dir(mut self, dir: impl Into<PathBuf>) -> Self {
+        self.current_dir = Some(dir.into());
+        self
+    }
+
+    /// Returns the program and its arguments separated by spaces.
+    ///
+    /// The program and arguments that are empty or contain whitespace, quotes
+    /// or backslashes are enclosed in double quotes, with quotes and
+    /// backslashes escaped by a backslash. Thus, invocations with different
+    /// arguments always have different command lines.
+    ///
+    /// The command line is used as key for the responses configured for a
+    /// nulled [`CommandRunner`].
+    #[must_use]
+    pub fn command_line(&self) -> String {
+        let mut command_line = quoted(&self.program);
+        for arg in &self.args {
+            command_line.push(' ');
+            command_line.push_str(&quoted(arg));
+        }
+        command_line
+    }
+}
+
+fn quoted(word: &str) -> String {
+    let needs_quotes = word.is_empty()
+        || word
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '"' | '\'' | '\\'));
+    if !needs_quotes {
+        return word.to_string();
+    }
+    let mut quoted = String::with_capacity(word.len() + 2);
+    quoted.push('"');
+    for c in word.chars() {
+        if matches!(c, '"' | '\\') {
+            quoted.push('\\');
+        }
+        quoted.push(c);
+    }
+    quoted.push('"');
+    quoted
+}
+
+/// The output of a command run by a [`CommandRunner`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CommandOutput {
+    /// The exit code of the command or `None` if it has been terminated by a
+    /// signal.
+    pub exit_code: Option<i32>,
+    /// The data the command has written to its standard output.
+    pub stdout: Vec<u8>,
+    /// The data the command has written to its standard error.
+    pub stderr: Vec<u8>,
+}
+
+impl Default for CommandOutput {
+    fn default() -> Self {
+        Self::success("")
+    }
+}
+
+impl CommandOutput {
+    /// Constructs the output of a successful command that has written the
+    /// given data to its standard output.
+    pub fn success(stdout: impl Into<Vec<u8>>) -> Self {
+        Self {
+            exit_code: Some(0),
+            stdout: stdout.into(),
+            stderr: Vec::new(),
+        }
+    }
+
+    /// Constructs the output of a failed command with the given exit code
+    /// that has written the given data to its standard error.
+    pub fn failure(exit_code: i32, stderr: impl Into<Vec<u8>>) -> Self {
+        Self {
+            exit_code: Some(exit_code),
+            stdout: Vec::new(),
+            stderr: stderr.into(),
+        }
+    }
+
+    /// Returns whether the command has exited with exit code 0.
+    #[must_use]
+    pub fn is_success(&self) -> bool {
+        self.exit_code == Some(0)
+    }
+}
+
+/// A nullable runner of external commands.
+///
+/// A runner created by [`CommandRunner::new()`] runs commands via
+/// [`std::process::Command`]. A runner created by [`CommandRunner::nulled()`]
+/// never runs any command but returns the output configured for the command
+/// line of the invocation. Commands without configured responses succeed
+/// without any output.
+///
+/// Every invocation is emitted as a [`CommandInvocation`], whether running the
+/// command succeeds or not.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::nullables::{CommandInvocation, CommandOutput, CommandRunner};
+/// use output_tracker::threadsafe::ConfigurableResponses;
+///
+/// let runner = CommandRunner::nulled_with([(
+///     "git status",
+///     ConfigurableResponses::always("git status", CommandOutput::failure(128, "not a git repository")),
+/// )]);
+/// let tracker = runner.track_invocations().unwrap();
+///
+/// let output = runner.run(&CommandInvocation::new("git").arg("status")).unwrap();
+///
+/// assert_eq!(output.exit_code, Some(128));
+/// assert_eq!(
+///     tracker.output().unwrap(),
+///     vec![CommandInvocation::new("git").arg("status")]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct CommandRunner {
+    executor: Executor,
+    subject: OutputSubject<CommandInvocation>,
+}
+
+#[derive(Debug, Clone)]
+enum Executor {
+    Real,
+    Nulled(Arc<HashMap<String, ConfigurableResponses<CommandOutput>>>),
+}
+
+impl Default for CommandRunner {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CommandRunner {
+    /// Constructs a runner that runs commands as child processes.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            executor: Executor::Real,
+            subject: OutputSubject::new(),
+        }
+    }
+
+    /// Constructs a nulled runner where every command succeeds without any
+    /// output.
+    #[must_use]
+    pub fn nulled() -> Self {
+        Self::nulled_with(Vec::<(String, _)>::new())
+    }
+
+    /// Constructs a nulled runner that returns the configured responses for
+    /// the given command lines.
+    ///
+    /// The command line of an invocation is the program followed by its
+    /// arguments separated by spaces, where arguments containing whitespace
+    /// are quoted, as returned by [`CommandInvocation::command_line()`].
+    pub fn nulled_with(
+        responses: impl IntoIterator<Item = (impl Into<String>, ConfigurableResponses<CommandOutput>)>,
+    ) -> Self {
+        let responses = responses
+            .into_iter()
+            .map(|(command_line, responses)| (command_line.into(), responses))
+            .collect();
+        Self {
+            executor: Executor::Nulled(Arc::new(responses)),
+            subject: OutputSubject::new(),
+        }
+    }
+
+    /// Creates an [`OutputTracker`] that tracks the invocations of commands
+    /// by this runner.
+    pub fn track_invocations(&self) -> Result<OutputTracker<CommandInvocation>, Error> {
+        self.subject.create_tracker()
+    }
+
+    /// Runs the given command and waits for it to finish.
+    pub fn run(&self, invocation: &CommandInvocation) -> io::Result<CommandOutput> {
+        _ = self.subject.emit(invocation.clone());
+        match &self.executor {
+            Executor::Real => {
+                let mut command = Command::new(&invocation.program);
+                command
+                    .args(&invocation.args)
+                    .envs(invocation.env.iter().map(|(key, value)| (key, value)));
+                if let Some(dir) = &invocation.current_dir {
+                    command.current_dir(dir);
+                }
+                command.output().map(|output| CommandOutput {
+                    exit_code: output.status.code(),
+                    stdout: output.stdout,
+                    stderr: output.stderr,
+                })
+            },
+            Executor::Nulled(responses) => responses
+                .get(&invocation.command_line())
+                .map_or_else(|| Ok(CommandOutput::default()), ConfigurableResponses::next)
+                .map_err(io::Error::other),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests;
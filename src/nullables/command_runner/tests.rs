@@ -0,0 +1,113 @@
+use super::*;
+use asserting::prelude::*;
+
+#[test]
+fn a_nulled_command_runner_succeeds_without_output_for_commands_without_responses() {
+    let runner = CommandRunner::nulled();
+
+    let output = runner.run(&CommandInvocation::new("make").arg("all"));
+
+    assert_that!(output.ok()).is_equal_to(Some(CommandOutput::success("")));
+}
+
+#[test]
+fn a_nulled_command_runner_returns_the_responses_configured_for_a_command_line() {
+    let runner = CommandRunner::nulled_with([(
+        "git rev-parse HEAD",
+        ConfigurableResponses::sequence(
+            "git rev-parse HEAD",
+            [
+                CommandOutput::success("abc123\n"),
+                CommandOutput::failure(128, "fatal"),
+            ],
+        ),
+    )]);
+    let invocation = CommandInvocation::new("git").args(["rev-parse", "HEAD"]);
+
+    assert_that!(runner.run(&invocation).ok())
+        .is_equal_to(Some(CommandOutput::success("abc123\n")));
+    assert_that!(runner.run(&invocation).ok())
+        .is_equal_to(Some(CommandOutput::failure(128, "fatal")));
+}
+
+#[test]
+fn empty_arguments_and_arguments_containing_whitespace_or_quotes_are_quoted_in_the_command_line() {
+    let invocation = CommandInvocation::new("git").args(["commit", "-m", "say \"hi\" \\ bye", ""]);
+
+    assert_that!(invocation.command_line())
+        .is_equal_to(r#"git commit -m "say \"hi\" \\ bye" """#.to_string());
+}
+
+#[test]
+fn a_nulled_command_runner_distinguishes_arguments_containing_whitespace_from_separate_arguments() {
+    let runner = CommandRunner::nulled_with([
+        (
+            r#"git commit -m "initial commit""#,
+            ConfigurableResponses::always("one argument", CommandOutput::success("one")),
+        ),
+        (
+            "git commit -m initial commit",
+            ConfigurableResponses::always("two arguments", CommandOutput::success("two")),
+        ),
+    ]);
+
+    let one = runner.run(&CommandInvocation::new("git").args(["commit", "-m", "initial commit"]));
+    let two =
+        runner.run(&CommandInvocation::new("git").args(["commit", "-m", "initial", "commit"]));
+
+    assert_that!(one.ok()).is_equal_to(Some(CommandOutput::success("one")));
+    assert_that!(two.ok()).is_equal_to(Some(CommandOutput::success("two")));
+}
+
+#[test]
+fn running_a_command_fails_when_its_configured_responses_are_exhausted() {
+    let runner = CommandRunner::nulled_with([(
+        "ls",
+        ConfigurableResponses::sequence("ls", [CommandOutput::success("file.txt")]),
+    )]);
+    let invocation = CommandInvocation::new("ls");
+
+    _ = runner.run(&invocation);
+    let output = runner.run(&invocation);
+
+    assert_that!(output.map_err(|err| err.to_string()))
+        .err()
+        .is_equal_to("no more responses configured for ls".to_string());
+}
+
+#[test]
+fn every_invocation_of_a_command_is_tracked() {
+    let runner = CommandRunner::nulled();
+    let tracker = runner
+        .track_invocations()
+        .unwrap_or_else(|err| panic!("could not create invocation tracker: {err}"));
+    let build = CommandInvocation::new("cargo")
+        .arg("build")
+        .env("RUSTFLAGS", "-D warnings")
+        .current_dir("/workspace");
+    let test = CommandInvocation::new("cargo").arg("test");
+
+    _ = runner.run(&build);
+    _ = runner.run(&test);
+
+    assert_that!(tracker.output())
+        .ok()
+        .contains_exactly([build, test]);
+}
+
+#[cfg(unix)]
+#[test]
+fn a_real_command_runner_runs_the_command_as_child_process() {
+    let runner = CommandRunner::new();
+    let invocation = CommandInvocation::new("sh")
+        .args(["-c", "echo $GREETING; exit 3"])
+        .env("GREETING", "hello");
+
+    let output = runner.run(&invocation);
+
+    assert_that!(output.ok()).is_equal_to(Some(CommandOutput {
+        exit_code: Some(3),
+        stdout: b"hello\n".to_vec(),
+        stderr: Vec::new(),
+    }));
+}
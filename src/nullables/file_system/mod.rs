@@ -0,0 +1,296 @@
+use crate::threadsafe::{Error, OutputSubject, OutputTracker};
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+/// An action performed on a [`FileSystem`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FsAction {
+    /// The file at the given path has been read.
+    Read(PathBuf),
+    /// The given contents have been written to the file at the given path.
+    Write {
+        /// The path of the written file.
+        path: PathBuf,
+        /// The contents written to the file.
+        contents: Vec<u8>,
+    },
+    /// The directory at the given path has been created along with all of its
+    /// missing parent directories.
+    CreateDirAll(PathBuf),
+    /// The file or directory at the given path has been removed.
+    Remove(PathBuf),
+}
+
+/// A nullable wrapper around the file system.
+///
+/// A file system created by [`FileSystem::new()`] delegates to the functions
+/// of [`std::fs`]. A file system created by [`FileSystem::nulled()`] holds all
+/// files and directories in memory and never touches the real file system.
+///
+/// Each successful call to [`read()`][FileSystem::read],
+/// [`write()`][FileSystem::write],
+/// [`create_dir_all()`][FileSystem::create_dir_all] or
+/// [`remove()`][FileSystem::remove] is emitted as an [`FsAction`]. Failed calls
+/// and calls to [`exists()`][FileSystem::exists] are not tracked.
+///
+/// Clones of a file system share the same files and emit their actions to the
+/// same trackers.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::nullables::{FileSystem, FsAction};
+/// use std::path::PathBuf;
+///
+/// let file_system = FileSystem::nulled();
+/// let tracker = file_system.track_actions().unwrap();
+///
+/// file_system.create_dir_all("reports").unwrap();
+/// file_system.write("reports/monthly.txt", "all good").unwrap();
+///
+/// assert_eq!(file_system.read("reports/monthly.txt").unwrap(), b"all good");
+/// assert_eq!(
+///     tracker.output().unwrap(),
+///     vec![
+///         FsAction::CreateDirAll(PathBuf::from("reports")),
+///         FsAction::Write {
+///             path: PathBuf::from("reports/monthly.txt"),
+///             contents: b"all good".to_vec(),
+///         },
+///         FsAction::Read(PathBuf::from("reports/monthly.txt")),
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct FileSystem {
+    storage: Storage,
+    subject: OutputSubject<FsAction>,
+}
+
+#[derive(Debug, Clone)]
+enum Storage {
+    Real,
+    Nulled(Arc<Mutex<MemoryTree>>),
+}
+
+impl Default for FileSystem {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl FileSystem {
+    /// Constructs a file system that delegates to the real file system.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            storage: Storage::Real,
+            subject: OutputSubject::new(),
+        }
+    }
+
+    /// Constructs a nulled file system that is empty.
+    #[must_use]
+    pub fn nulled() -> Self {
+        Self::nulled_with_files(Vec::<(PathBuf, Vec<u8>)>::new())
+    }
+
+    /// Constructs a nulled file system holding the given files.
+    ///
+    /// The parent directories of the given files are created as well.
+    pub fn nulled_with_files(
+        files: impl IntoIterator<Item = (impl AsRef<Path>, impl AsRef<[u8]>)>,
+    ) -> Self {
+        let mut tree = MemoryTree::default();
+        for (path, contents) in files {
+            tree.insert_file(path.as_ref(), contents.as_ref());
+        }
+        Self {
+            storage: Storage::Nulled(Arc::new(Mutex::new(tree))),
+            subject: OutputSubject::new(),
+        }
+    }
+
+    /// Creates an [`OutputTracker`] that tracks the actions performed on this
+    /// file system.
+    pub fn track_actions(&self) -> Result<OutputTracker<FsAction>, Error> {
+        self.subject.create_tracker()
+    }
+
+    /// Reads the entire contents of the file at the given path.
+    pub fn read(&self, path: impl AsRef<Path>) -> io::Result<Vec<u8>> {
+        let path = path.as_ref();
+        let contents = match &self.storage {
+            Storage::Real => fs::read(path),
+            Storage::Nulled(tree) => lock(tree).read(path),
+        }?;
+        _ = self.subject.emit(FsAction::Read(path.to_path_buf()));
+        Ok(contents)
+    }
+
+    /// Writes the given contents to the file at the given path.
+    ///
+    /// The file is created if it does not exist and its contents are replaced
+    /// if it does.
+    pub fn write(&self, path: impl AsRef<Path>, contents: impl AsRef<[u8]>) -> io::Result<()> {
+        let (path, contents) = (path.as_ref(), contents.as_ref());
+        match &self.storage {
+            Storage::Real => fs::write(path, contents),
+            Storage::Nulled(tree) => lock(tree).write(path, contents),
+        }?;
+        _ = self.subject.emit(FsAction::Write {
+            path: path.to_path_buf(),
+            contents: contents.to_vec(),
+        });
+        Ok(())
+    }
+
+    /// Creates the directory at the given path along with all of its missing
+    /// parent directories.
+    pub fn create_dir_all(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match &self.storage {
+            Storage::Real => fs::create_dir_all(path),
+            Storage::Nulled(tree) => lock(tree).create_dir_all(path),
+        }?;
+        _ = self
+            .subject
+            .emit(FsAction::CreateDirAll(path.to_path_buf()));
+        Ok(())
+    }
+
+    /// Removes the file or the directory with all of its contents at the given
+    /// path.
+    pub fn remove(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let path = path.as_ref();
+        match &self.storage {
+            Storage::Real => {
+                if path.is_dir() {
+                    fs::remove_dir_all(path)
+                } else {
+                    fs::remove_file(path)
+                }
+            },
+            Storage::Nulled(tree) => lock(tree).remove(path),
+        }?;
+        _ = self.subject.emit(FsAction::Remove(path.to_path_buf()));
+        Ok(())
+    }
+
+    /// Returns whether a file or directory exists at the given path.
+    pub fn exists(&self, path: impl AsRef<Path>) -> bool {
+        let path = path.as_ref();
+        match &self.storage {
+            Storage::Real => path.exists(),
+            Storage::Nulled(tree) => lock(tree).exists(path),
+        }
+    }
+}
+
+fn lock(tree: &Mutex<MemoryTree>) -> MutexGuard<'_, MemoryTree> {
+    tree.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    File(Vec<u8>),
+    Dir,
+}
+
+#[derive(Debug, Default)]
+struct MemoryTree {
+    nodes: BTreeMap<PathBuf, Node>,
+}
+
+impl MemoryTree {
+    fn insert_file(&mut self, path: &Path, contents: &[u8]) {
+        for ancestor in path.ancestors().skip(1) {
+            if !is_root(ancestor) {
+                _ = self.nodes.insert(ancestor.to_path_buf(), Node::Dir);
+            }
+        }
+        _ = self
+            .nodes
+            .insert(path.to_path_buf(), Node::File(contents.to_vec()));
+    }
+
+    fn read(&self, path: &Path) -> io::Result<Vec<u8>> {
+        match self.nodes.get(path) {
+            Some(Node::File(contents)) => Ok(contents.clone()),
+            Some(Node::Dir) => Err(is_a_directory(path)),
+            None => Err(not_found(path)),
+        }
+    }
+
+    fn write(&mut self, path: &Path, contents: &[u8]) -> io::Result<()> {
+        if self.nodes.get(path) == Some(&Node::Dir) {
+            return Err(is_a_directory(path));
+        }
+        match path.parent() {
+            Some(parent) if !is_root(parent) && self.nodes.get(parent) != Some(&Node::Dir) => {
+                Err(not_found(parent))
+            },
+            _ => {
+                _ = self
+                    .nodes
+                    .insert(path.to_path_buf(), Node::File(contents.to_vec()));
+                Ok(())
+            },
+        }
+    }
+
+    fn create_dir_all(&mut self, path: &Path) -> io::Result<()> {
+        let missing = path
+            .ancestors()
+            .filter(|ancestor| !is_root(ancestor))
+            .map(|ancestor| match self.nodes.get(ancestor) {
+                Some(Node::File(_)) => Err(io::Error::new(
+                    ErrorKind::AlreadyExists,
+                    format!("{} is a file", ancestor.display()),
+                )),
+                Some(Node::Dir) => Ok(None),
+                None => Ok(Some(ancestor.to_path_buf())),
+            })
+            .collect::<io::Result<Vec<_>>>()?;
+        for directory in missing.into_iter().flatten() {
+            _ = self.nodes.insert(directory, Node::Dir);
+        }
+        Ok(())
+    }
+
+    fn remove(&mut self, path: &Path) -> io::Result<()> {
+        if self.nodes.remove(path).is_none() {
+            return Err(not_found(path));
+        }
+        self.nodes.retain(|entry, _| !entry.starts_with(path));
+        Ok(())
+    }
+
+    fn exists(&self, path: &Path) -> bool {
+        is_root(path) || self.nodes.contains_key(path)
+    }
+}
+
+fn is_root(path: &Path) -> bool {
+    path.parent().is_none() || path.as_os_str().is_empty()
+}
+
+fn not_found(path: &Path) -> io::Error {
+    io::Error::new(
+        ErrorKind::NotFound,
+        format!("{} does not exist", path.display()),
+    )
+}
+
+fn is_a_directory(path: &Path) -> io::Error {
+    io::Error::new(
+        ErrorKind::InvalidInput,
+        format!("{} is a directory", path.display()),
+    )
+}
+
+#[cfg(test)]
+mod tests;
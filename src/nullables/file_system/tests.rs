@@ -0,0 +1,128 @@
+use super::*;
+use asserting::prelude::*;
+use std::env;
+use std::process;
+
+#[test]
+fn a_nulled_file_system_reads_the_contents_written_to_a_file() {
+    let file_system = FileSystem::nulled();
+
+    file_system
+        .write("greeting.txt", "hello")
+        .unwrap_or_else(|err| panic!("could not write file: {err}"));
+
+    assert_that!(file_system.read("greeting.txt").ok()).is_equal_to(Some(b"hello".to_vec()));
+}
+
+#[test]
+fn a_nulled_file_system_holds_the_files_it_has_been_created_with() {
+    let file_system = FileSystem::nulled_with_files([("config/app.toml", "debug = true")]);
+
+    assert_that!(file_system.exists("config")).is_true();
+    assert_that!(file_system.read("config/app.toml").ok())
+        .is_equal_to(Some(b"debug = true".to_vec()));
+}
+
+#[test]
+fn reading_a_missing_file_from_a_nulled_file_system_fails_with_not_found() {
+    let file_system = FileSystem::nulled();
+
+    let result = file_system.read("missing.txt");
+
+    assert_that!(result.map_err(|err| err.kind()))
+        .err()
+        .is_equal_to(ErrorKind::NotFound);
+}
+
+#[test]
+fn writing_a_file_into_a_missing_directory_of_a_nulled_file_system_fails() {
+    let file_system = FileSystem::nulled();
+
+    let result = file_system.write("reports/monthly.txt", "all good");
+
+    assert_that!(result.map_err(|err| err.kind()))
+        .err()
+        .is_equal_to(ErrorKind::NotFound);
+    assert_that!(file_system.exists("reports/monthly.txt")).is_false();
+}
+
+#[test]
+fn creating_directories_in_a_nulled_file_system_creates_all_missing_parents() {
+    let file_system = FileSystem::nulled();
+
+    file_system
+        .create_dir_all("a/b/c")
+        .unwrap_or_else(|err| panic!("could not create directories: {err}"));
+
+    assert_that!(file_system.exists("a")).is_true();
+    assert_that!(file_system.exists("a/b")).is_true();
+    assert_that!(file_system.exists("a/b/c")).is_true();
+}
+
+#[test]
+fn removing_a_directory_from_a_nulled_file_system_removes_all_of_its_contents() {
+    let file_system = FileSystem::nulled_with_files([
+        ("data/a.txt", "a"),
+        ("data/sub/b.txt", "b"),
+        ("keep.txt", "c"),
+    ]);
+
+    file_system
+        .remove("data")
+        .unwrap_or_else(|err| panic!("could not remove directory: {err}"));
+
+    assert_that!(file_system.exists("data")).is_false();
+    assert_that!(file_system.exists("data/a.txt")).is_false();
+    assert_that!(file_system.exists("data/sub/b.txt")).is_false();
+    assert_that!(file_system.exists("keep.txt")).is_true();
+}
+
+#[test]
+fn successful_actions_on_a_file_system_are_tracked() {
+    let file_system = FileSystem::nulled();
+    let tracker = file_system
+        .track_actions()
+        .unwrap_or_else(|err| panic!("could not create file system tracker: {err}"));
+
+    file_system
+        .create_dir_all("out")
+        .unwrap_or_else(|err| panic!("could not create directory: {err}"));
+    file_system
+        .write("out/result.txt", "42")
+        .unwrap_or_else(|err| panic!("could not write file: {err}"));
+    _ = file_system.read("out/missing.txt");
+    _ = file_system.exists("out/result.txt");
+    file_system
+        .remove("out/result.txt")
+        .unwrap_or_else(|err| panic!("could not remove file: {err}"));
+
+    assert_that!(tracker.output()).ok().contains_exactly([
+        FsAction::CreateDirAll(PathBuf::from("out")),
+        FsAction::Write {
+            path: PathBuf::from("out/result.txt"),
+            contents: b"42".to_vec(),
+        },
+        FsAction::Remove(PathBuf::from("out/result.txt")),
+    ]);
+}
+
+#[test]
+fn a_real_file_system_delegates_to_the_file_system_of_the_operating_system() {
+    let base_dir = env::temp_dir().join(format!("output-tracker-fs-test-{}", process::id()));
+    let file_path = base_dir.join("nested").join("file.txt");
+    let file_system = FileSystem::new();
+
+    file_system
+        .create_dir_all(base_dir.join("nested"))
+        .unwrap_or_else(|err| panic!("could not create directories: {err}"));
+    file_system
+        .write(&file_path, "real contents")
+        .unwrap_or_else(|err| panic!("could not write file: {err}"));
+    let contents = file_system.read(&file_path).ok();
+    file_system
+        .remove(&base_dir)
+        .unwrap_or_else(|err| panic!("could not remove directory: {err}"));
+
+    assert_that!(contents).is_equal_to(Some(b"real contents".to_vec()));
+    assert_that!(file_system.exists(&base_dir)).is_false();
+}
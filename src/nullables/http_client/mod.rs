@@ -0,0 +1,255 @@
+use crate::threadsafe::{ConfigurableResponses, Error, OutputSubject, OutputTracker};
+use std::collections::HashMap;
+use std::fmt::{self, Debug};
+use std::io;
+use std::sync::Arc;
+
+/// A request sent by an [`HttpClient`].
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::nullables::HttpRequest;
+///
+/// let request = HttpRequest::post("https://api.example.com/users?notify=true")
+///     .header("Content-Type", "application/json")
+///     .body(r#"{"name":"Alice"}"#);
+///
+/// assert_eq!(request.route(), "POST /users");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// The HTTP method of the request, e.g. `GET` or `POST`.
+    pub method: String,
+    /// The URL the request is sent to.
+    pub url: String,
+    /// The headers of the request.
+    pub headers: Vec<(String, String)>,
+    /// The body of the request.
+    pub body: Vec<u8>,
+}
+
+impl HttpRequest {
+    /// Constructs a request with the given method to the given URL.
+    pub fn new(method: impl Into<String>, url: impl Into<String>) -> Self {
+        Self {
+            method: method.into(),
+            url: url.into(),
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Constructs a `GET` request to the given URL.
+    pub fn get(url: impl Into<String>) -> Self {
+        Self::new("GET", url)
+    }
+
+    /// Constructs a `POST` request to the given URL.
+    pub fn post(url: impl Into<String>) -> Self {
+        Self::new("POST", url)
+    }
+
+    /// Constructs a `PUT` request to the given URL.
+    pub fn put(url: impl Into<String>) -> Self {
+        Self::new("PUT", url)
+    }
+
+    /// Constructs a `DELETE` request to the given URL.
+    pub fn delete(url: impl Into<String>) -> Self {
+        Self::new("DELETE", url)
+    }
+
+    /// Adds a header to this request.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of this request.
+    #[must_use]
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+
+    /// Returns the route of this request.
+    ///
+    /// The route is the method followed by the path of the URL, without the
+    /// scheme, the host and the query, e.g. `GET /users/42`. It is used as key
+    /// for the responses configured for a nulled [`HttpClient`].
+    #[must_use]
+    pub fn route(&self) -> String {
+        let without_scheme = self
+            .url
+            .split_once("://")
+            .map_or(self.url.as_str(), |(_, rest)| rest);
+        let path = without_scheme
+            .find('/')
+            .map_or("/", |start| &without_scheme[start..]);
+        let path = path.split(['?', '#']).next().unwrap_or(path);
+        format!("{} {path}", self.method)
+    }
+}
+
+/// A response received by an [`HttpClient`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpResponse {
+    /// The status code of the response.
+    pub status: u16,
+    /// The headers of the response.
+    pub headers: Vec<(String, String)>,
+    /// The body of the response.
+    pub body: Vec<u8>,
+}
+
+impl Default for HttpResponse {
+    fn default() -> Self {
+        Self::new(200)
+    }
+}
+
+impl HttpResponse {
+    /// Constructs a response with the given status code and an empty body.
+    #[must_use]
+    pub const fn new(status: u16) -> Self {
+        Self {
+            status,
+            headers: Vec::new(),
+            body: Vec::new(),
+        }
+    }
+
+    /// Adds a header to this response.
+    #[must_use]
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Sets the body of this response.
+    #[must_use]
+    pub fn body(mut self, body: impl Into<Vec<u8>>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// Sends HTTP requests on behalf of an [`HttpClient`].
+///
+/// This trait is the adapter to the HTTP library used in production code. It
+/// is implemented for the HTTP library of choice and passed to
+/// [`HttpClient::new()`].
+pub trait HttpTransport: Send + Sync {
+    /// Sends the given request and returns the received response.
+    fn send(&self, request: &HttpRequest) -> io::Result<HttpResponse>;
+}
+
+/// A nullable HTTP client.
+///
+/// A client created by [`HttpClient::new()`] sends requests via the given
+/// [`HttpTransport`]. A client created by [`HttpClient::nulled()`] never sends
+/// any request but returns the responses configured for the route of the
+/// request. Requests to routes without configured responses are answered with
+/// status code 200 and an empty body.
+///
+/// Every request is emitted as an [`HttpRequest`], whether sending it succeeds
+/// or not.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::nullables::{HttpClient, HttpRequest, HttpResponse};
+/// use output_tracker::threadsafe::ConfigurableResponses;
+///
+/// let client = HttpClient::nulled_with([(
+///     "GET /users/42",
+///     ConfigurableResponses::always("GET /users/42", HttpResponse::new(404)),
+/// )]);
+/// let tracker = client.track_requests().unwrap();
+///
+/// let response = client
+///     .send(&HttpRequest::get("https://api.example.com/users/42"))
+///     .unwrap();
+///
+/// assert_eq!(response.status, 404);
+/// assert_eq!(
+///     tracker.output().unwrap(),
+///     vec![HttpRequest::get("https://api.example.com/users/42")]
+/// );
+/// ```
+#[derive(Clone)]
+pub struct HttpClient {
+    transport: Arc<dyn HttpTransport>,
+    subject: OutputSubject<HttpRequest>,
+}
+
+impl Debug for HttpClient {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HttpClient")
+            .field("subject", &self.subject)
+            .finish_non_exhaustive()
+    }
+}
+
+impl HttpClient {
+    /// Constructs a client that sends requests via the given transport.
+    pub fn new(transport: impl HttpTransport + 'static) -> Self {
+        Self {
+            transport: Arc::new(transport),
+            subject: OutputSubject::new(),
+        }
+    }
+
+    /// Constructs a nulled client that answers every request with status code
+    /// 200 and an empty body.
+    #[must_use]
+    pub fn nulled() -> Self {
+        Self::nulled_with(Vec::<(String, _)>::new())
+    }
+
+    /// Constructs a nulled client that returns the configured responses for
+    /// the given routes.
+    ///
+    /// The route of a request is its method followed by the path of its URL,
+    /// as returned by [`HttpRequest::route()`].
+    pub fn nulled_with(
+        responses: impl IntoIterator<Item = (impl Into<String>, ConfigurableResponses<HttpResponse>)>,
+    ) -> Self {
+        Self::new(NulledTransport {
+            responses: responses
+                .into_iter()
+                .map(|(route, responses)| (route.into(), responses))
+                .collect(),
+        })
+    }
+
+    /// Creates an [`OutputTracker`] that tracks the requests sent by this
+    /// client.
+    pub fn track_requests(&self) -> Result<OutputTracker<HttpRequest>, Error> {
+        self.subject.create_tracker()
+    }
+
+    /// Sends the given request and returns the received response.
+    pub fn send(&self, request: &HttpRequest) -> io::Result<HttpResponse> {
+        _ = self.subject.emit(request.clone());
+        self.transport.send(request)
+    }
+}
+
+struct NulledTransport {
+    responses: HashMap<String, ConfigurableResponses<HttpResponse>>,
+}
+
+impl HttpTransport for NulledTransport {
+    fn send(&self, request: &HttpRequest) -> io::Result<HttpResponse> {
+        self.responses
+            .get(&request.route())
+            .map_or_else(|| Ok(HttpResponse::default()), ConfigurableResponses::next)
+            .map_err(io::Error::other)
+    }
+}
+
+#[cfg(test)]
+mod tests;
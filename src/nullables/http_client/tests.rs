@@ -0,0 +1,81 @@
+use super::*;
+use asserting::prelude::*;
+
+struct EchoTransport;
+
+impl HttpTransport for EchoTransport {
+    fn send(&self, request: &HttpRequest) -> io::Result<HttpResponse> {
+        Ok(HttpResponse::new(201).body(request.body.clone()))
+    }
+}
+
+#[test]
+fn the_route_of_a_request_consists_of_the_method_and_the_path_of_the_url() {
+    assert_that!(HttpRequest::get("https://example.com/users/42?verbose=true").route())
+        .is_equal_to("GET /users/42".to_string());
+    assert_that!(HttpRequest::delete("http://localhost:8080/items#top").route())
+        .is_equal_to("DELETE /items".to_string());
+    assert_that!(HttpRequest::post("https://example.com").route())
+        .is_equal_to("POST /".to_string());
+    assert_that!(HttpRequest::put("/relative/path").route())
+        .is_equal_to("PUT /relative/path".to_string());
+}
+
+#[test]
+fn a_nulled_http_client_answers_requests_to_routes_without_responses_with_status_200() {
+    let client = HttpClient::nulled();
+
+    let response = client.send(&HttpRequest::get("https://example.com/health"));
+
+    assert_that!(response.ok()).is_equal_to(Some(HttpResponse::new(200)));
+}
+
+#[test]
+fn a_nulled_http_client_returns_the_responses_configured_for_a_route() {
+    let client = HttpClient::nulled_with([(
+        "POST /orders",
+        ConfigurableResponses::sequence(
+            "POST /orders",
+            [
+                HttpResponse::new(201).body("order 1"),
+                HttpResponse::new(409),
+            ],
+        ),
+    )]);
+    let request = HttpRequest::post("https://shop.example.com/orders?dry-run=false");
+
+    assert_that!(client.send(&request).ok())
+        .is_equal_to(Some(HttpResponse::new(201).body("order 1")));
+    assert_that!(client.send(&request).ok()).is_equal_to(Some(HttpResponse::new(409)));
+    assert_that!(client.send(&request).map_err(|err| err.to_string()))
+        .err()
+        .is_equal_to("no more responses configured for POST /orders".to_string());
+}
+
+#[test]
+fn an_http_client_sends_requests_via_its_transport() {
+    let client = HttpClient::new(EchoTransport);
+
+    let response = client.send(&HttpRequest::post("https://example.com/echo").body("ping"));
+
+    assert_that!(response.ok()).is_equal_to(Some(HttpResponse::new(201).body("ping")));
+}
+
+#[test]
+fn every_request_sent_by_an_http_client_is_tracked() {
+    let client = HttpClient::nulled();
+    let tracker = client
+        .track_requests()
+        .unwrap_or_else(|err| panic!("could not create request tracker: {err}"));
+    let create = HttpRequest::post("https://example.com/users")
+        .header("Content-Type", "application/json")
+        .body(r#"{"name":"Alice"}"#);
+    let fetch = HttpRequest::get("https://example.com/users/1");
+
+    _ = client.send(&create);
+    _ = client.send(&fetch);
+
+    assert_that!(tracker.output())
+        .ok()
+        .contains_exactly([create, fetch]);
+}
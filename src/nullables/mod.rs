@@ -0,0 +1,23 @@
+//! Nullable infrastructure wrappers whose usage is tracked.
+//!
+//! Each wrapper comes with a real implementation that talks to the outside
+//! world and a nulled implementation that is used in tests. Both
+//! implementations emit the actions performed on the wrapper through an
+//! [`OutputSubject`](crate::threadsafe::OutputSubject), so that tests can
+//! assert on them via an [`OutputTracker`](crate::threadsafe::OutputTracker).
+//!
+//! The wrappers are based on the threadsafe variant of the output-tracker.
+
+mod clock;
+mod command_runner;
+#[cfg(feature = "nullables-fs")]
+mod file_system;
+#[cfg(feature = "nullables-http")]
+mod http_client;
+
+pub use clock::{Clock, ClockAction};
+pub use command_runner::{CommandInvocation, CommandOutput, CommandRunner};
+#[cfg(feature = "nullables-fs")]
+pub use file_system::{FileSystem, FsAction};
+#[cfg(feature = "nullables-http")]
+pub use http_client::{HttpClient, HttpRequest, HttpResponse, HttpTransport};
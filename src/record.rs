@@ -0,0 +1,235 @@
+use crate::tracking_scope::current_correlation_id;
+use std::cell::OnceCell;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::thread::{self, ThreadId};
+use std::time::Instant;
+
+static RECORD_SEQUENCE: AtomicU64 = AtomicU64::new(0);
+
+/// An item of data tracked by a recording tracker together with the moment it
+/// has been emitted and the thread that emitted it.
+///
+/// Records are collected by trackers created with the function
+/// `OutputSubject::create_recording_tracker()`. The sequence number of a
+/// record increases with each recorded emission, regardless of the subject
+/// that emitted the data. Comparing the sequence numbers of records tracked
+/// from different subjects tells in which order the data has been emitted.
+/// All recording trackers of one subject get the same sequence number and
+/// timestamp for the same emission, and the records of a tracker are always
+/// ordered by their sequence numbers, even if the data is emitted by several
+/// threads concurrently.
+///
+/// The records of a tracker can be grouped by the emitting thread with the
+/// function `OutputTracker::output_by_thread()`. If the data has been emitted
+/// within a `TrackingScope`, the record also holds the correlation id of the
+/// scope.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::OutputSubject;
+///
+/// let repository = OutputSubject::new();
+/// let mailer = OutputSubject::new();
+/// let inserts = repository.create_recording_tracker().unwrap();
+/// let emails = mailer.create_recording_tracker().unwrap();
+///
+/// repository.emit("insert user").unwrap();
+/// mailer.emit("send welcome email").unwrap();
+///
+/// let insert = &inserts.output().unwrap()[0];
+/// let email = &emails.output().unwrap()[0];
+///
+/// assert_eq!(insert.data(), &"insert user");
+/// assert!(insert.sequence() < email.sequence());
+/// assert!(insert.timestamp() <= email.timestamp());
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Record<M> {
+    sequence: u64,
+    timestamp: Instant,
+    thread_id: ThreadId,
+    thread_name: Option<Arc<str>>,
+    correlation_id: Option<Arc<str>>,
+    data: M,
+}
+
+impl<M> Record<M> {
+    /// Returns the sequence number of this record.
+    pub const fn sequence(&self) -> u64 {
+        self.sequence
+    }
+
+    /// Returns the moment the recorded data has been emitted.
+    pub const fn timestamp(&self) -> Instant {
+        self.timestamp
+    }
+
+    /// Returns the id of the thread that emitted the recorded data.
+    pub const fn thread_id(&self) -> ThreadId {
+        self.thread_id
+    }
+
+    /// Returns the name of the thread that emitted the recorded data, if the
+    /// thread has a name.
+    #[must_use]
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name.as_deref()
+    }
+
+    /// Returns the correlation id of the `TrackingScope` the data has been
+    /// emitted in, if any.
+    #[must_use]
+    pub fn correlation_id(&self) -> Option<&str> {
+        self.correlation_id.as_deref()
+    }
+
+    /// Returns a reference to the recorded data.
+    pub const fn data(&self) -> &M {
+        &self.data
+    }
+
+    /// Consumes this record and returns the recorded data.
+    pub fn into_data(self) -> M {
+        self.data
+    }
+}
+
+/// The sequence number and moment of one emission of data to the trackers of
+/// a subject.
+///
+/// The sequence number is taken when the first record of the emission is
+/// created, so that emissions without any recording tracker do not consume
+/// sequence numbers. All records created for the same emission share the
+/// sequence number and timestamp.
+///
+/// An emission can be ordered by a lock of the subject. The lock is acquired
+/// before the sequence number is taken and held until the emission is
+/// dropped, that is until the data has been delivered to all trackers. Thus
+/// the records of concurrent emissions reach each tracker of the subject in
+/// the order of their sequence numbers.
+#[derive(Debug, Default)]
+pub struct Emission<'a> {
+    order: Option<&'a Mutex<()>>,
+    stamp: OnceCell<Stamp<'a>>,
+}
+
+#[derive(Debug)]
+struct Stamp<'a> {
+    sequence: u64,
+    timestamp: Instant,
+    _order: Option<MutexGuard<'a, ()>>,
+}
+
+impl Emission<'_> {
+    /// Creates an emission that is not ordered by a lock.
+    pub const fn new() -> Self {
+        Self {
+            order: None,
+            stamp: OnceCell::new(),
+        }
+    }
+
+    /// Creates a record of the given data emitted by this emission.
+    pub fn record<M>(&self, data: M) -> Record<M> {
+        let stamp = self.stamp.get_or_init(|| {
+            // the lock only guards the order of emissions, a panic while it
+            // was held does not leave any data in an inconsistent state
+            let order = self
+                .order
+                .map(|order| order.lock().unwrap_or_else(PoisonError::into_inner));
+            Stamp {
+                sequence: RECORD_SEQUENCE.fetch_add(1, Ordering::AcqRel),
+                timestamp: Instant::now(),
+                _order: order,
+            }
+        });
+        let thread = thread::current();
+        Record {
+            sequence: stamp.sequence,
+            timestamp: stamp.timestamp,
+            thread_id: thread.id(),
+            thread_name: thread.name().map(Arc::from),
+            correlation_id: current_correlation_id(),
+            data,
+        }
+    }
+}
+
+#[cfg(feature = "_threadsafe-variant")]
+impl<'a> Emission<'a> {
+    /// Creates an emission that is ordered by the given lock.
+    pub const fn ordered_by(order: &'a Mutex<()>) -> Self {
+        Self {
+            order: Some(order),
+            stamp: OnceCell::new(),
+        }
+    }
+}
+
+/// The data tracked by a recording tracker that has been emitted by one
+/// thread.
+///
+/// It is returned by the function `OutputTracker::output_by_thread()`. The
+/// data emitted by one thread is always tracked in the order it has been
+/// emitted, even if the data emitted by different threads is interleaved in an
+/// arbitrary order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ThreadOutput<M> {
+    thread_id: ThreadId,
+    thread_name: Option<Arc<str>>,
+    output: Vec<M>,
+}
+
+impl<M> ThreadOutput<M> {
+    /// Returns the id of the thread that emitted the data.
+    #[must_use]
+    pub const fn thread_id(&self) -> ThreadId {
+        self.thread_id
+    }
+
+    /// Returns the name of the thread that emitted the data, if the thread
+    /// has a name.
+    #[must_use]
+    pub fn thread_name(&self) -> Option<&str> {
+        self.thread_name.as_deref()
+    }
+
+    /// Returns the data emitted by the thread in the order it has been
+    /// emitted.
+    #[must_use]
+    pub fn output(&self) -> &[M] {
+        &self.output
+    }
+
+    /// Consumes this group and returns the data emitted by the thread in the
+    /// order it has been emitted.
+    #[must_use]
+    pub fn into_output(self) -> Vec<M> {
+        self.output
+    }
+}
+
+/// Groups the given records by the thread that emitted them.
+///
+/// The groups are ordered by the first record of each thread. Within a group
+/// the data keeps the order of the given records.
+pub fn group_by_thread<M>(records: Vec<Record<M>>) -> Vec<ThreadOutput<M>> {
+    let mut groups: Vec<ThreadOutput<M>> = Vec::new();
+    for record in records {
+        if let Some(group) = groups
+            .iter_mut()
+            .find(|group| group.thread_id == record.thread_id)
+        {
+            group.output.push(record.data);
+        } else {
+            groups.push(ThreadOutput {
+                thread_id: record.thread_id,
+                thread_name: record.thread_name,
+                output: vec![record.data],
+            });
+        }
+    }
+    groups
+}
@@ -0,0 +1,229 @@
+//! Snapshot testing of the output of a tracker against golden files.
+//!
+//! A snapshot is the pretty-printed debug representation of the data tracked
+//! by an `OutputTracker` stored in a file named `<name>.snap`. The macro
+//! [`assert_output_snapshot!`] compares the current output of a tracker with
+//! the snapshot stored in the directory `tests/snapshots` of the crate under
+//! test.
+//!
+//! If the snapshot does not exist or does not match the output, the assertion
+//! fails and the actual output is written to the file `<name>.snap.new` next
+//! to the snapshot. The new file can be reviewed and renamed to `<name>.snap`
+//! to accept it. The assertion message shows a diff of the changed lines. If
+//! the output and the snapshot differ in too many lines to compute the diff
+//! quickly, the differing part is shown as removed lines followed by added
+//! lines.
+//!
+//! Running the tests with the environment variable
+//! `OUTPUT_TRACKER_UPDATE_SNAPSHOTS=1` writes the actual output to the
+//! snapshot files instead of failing the assertion.
+//!
+//! [`assert_output_snapshot!`]: crate::assert_output_snapshot
+
+use crate::diff::{diff, Change};
+use std::env;
+use std::fmt::{Debug, Write as _};
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Name of the environment variable that switches snapshot assertions to the
+/// update mode.
+///
+/// If the variable is set to `1`, `true` or `yes`, snapshot assertions write
+/// the actual output to the snapshot file instead of comparing it.
+pub const UPDATE_SNAPSHOTS_ENV: &str = "OUTPUT_TRACKER_UPDATE_SNAPSHOTS";
+
+/// Number of unchanged lines shown around each changed line of a diff.
+const DIFF_CONTEXT: usize = 2;
+
+/// Asserts that the given output matches the snapshot `name` stored in the
+/// directory `snapshot_dir`.
+///
+/// This is the function behind the macro
+/// [`assert_output_snapshot!`](crate::assert_output_snapshot), which should be
+/// preferred in tests. It can be used directly to store snapshots in a
+/// directory other than `tests/snapshots`.
+///
+/// # Panics
+///
+/// Panics if the snapshot does not exist or does not match the given output
+/// and the update mode is not enabled via [`UPDATE_SNAPSHOTS_ENV`]. Also
+/// panics if a snapshot file can not be read or written.
+#[track_caller]
+pub fn assert_output_snapshot<M>(output: &[M], snapshot_dir: impl AsRef<Path>, name: &str)
+where
+    M: Debug,
+{
+    let snapshot_file = snapshot_dir.as_ref().join(format!("{name}.snap"));
+    match verify_snapshot(output, &snapshot_file, update_mode_enabled()) {
+        Ok(Verification::Matched | Verification::Updated) => {},
+        Ok(Verification::Missing { new_file }) => panic!(
+            "snapshot {name:?} does not exist\n\nthe actual output has been written to {}\nreview \
+             it and rename it to {} to accept it, or run the tests with {UPDATE_SNAPSHOTS_ENV}=1",
+            new_file.display(),
+            snapshot_file.display(),
+        ),
+        Ok(Verification::Mismatched { diff, new_file }) => panic!(
+            "output does not match snapshot {name:?}\n\n{diff}\nthe actual output has been written \
+             to {}\nreview it and rename it to {} to accept it, or run the tests with \
+             {UPDATE_SNAPSHOTS_ENV}=1",
+            new_file.display(),
+            snapshot_file.display(),
+        ),
+        Err(err) => panic!(
+            "could not verify snapshot {name:?} at {}, reason: {err}",
+            snapshot_file.display()
+        ),
+    }
+}
+
+fn update_mode_enabled() -> bool {
+    env::var(UPDATE_SNAPSHOTS_ENV).is_ok_and(|value| matches!(value.trim(), "1" | "true" | "yes"))
+}
+
+#[derive(Debug, PartialEq, Eq)]
+enum Verification {
+    Matched,
+    Updated,
+    Missing { new_file: PathBuf },
+    Mismatched { diff: String, new_file: PathBuf },
+}
+
+fn verify_snapshot<M>(
+    output: &[M],
+    snapshot_file: &Path,
+    update: bool,
+) -> Result<Verification, io::Error>
+where
+    M: Debug,
+{
+    let actual = format!("{output:#?}\n");
+    let new_file = snapshot_file.with_extension("snap.new");
+
+    let expected = match fs::read_to_string(snapshot_file) {
+        Ok(expected) => Some(expected.replace("\r\n", "\n")),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => None,
+        Err(err) => return Err(err),
+    };
+
+    if expected.as_deref() == Some(actual.as_str()) {
+        remove_if_exists(&new_file)?;
+        return Ok(Verification::Matched);
+    }
+
+    if let Some(parent) = snapshot_file.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    if update {
+        fs::write(snapshot_file, actual)?;
+        remove_if_exists(&new_file)?;
+        return Ok(Verification::Updated);
+    }
+    fs::write(&new_file, &actual)?;
+
+    Ok(match expected {
+        None => Verification::Missing { new_file },
+        Some(expected) => Verification::Mismatched {
+            diff: diff_lines(&expected, &actual),
+            new_file,
+        },
+    })
+}
+
+fn remove_if_exists(file: &Path) -> Result<(), io::Error> {
+    match fs::remove_file(file) {
+        Err(err) if err.kind() != io::ErrorKind::NotFound => Err(err),
+        _ => Ok(()),
+    }
+}
+
+/// Renders a line based diff of the two texts, showing the changed lines
+/// with a few unchanged lines around them.
+///
+/// The diff is computed by [`diff`], so its cost is bounded by
+/// [`MAX_EDIT_DISTANCE`](crate::diff::MAX_EDIT_DISTANCE) even for long
+/// snapshots.
+fn diff_lines(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+    let lines = diff(&expected, &actual);
+    let is_shown = |index: usize| {
+        let start = index.saturating_sub(DIFF_CONTEXT);
+        let end = (index + DIFF_CONTEXT + 1).min(lines.len());
+        lines[start..end]
+            .iter()
+            .any(|line| !matches!(line, Change::Same(_)))
+    };
+
+    let mut rendered = String::from("--- snapshot\n+++ actual\n");
+    let mut skipped = false;
+    for (index, line) in lines.iter().enumerate() {
+        if !is_shown(index) {
+            skipped = true;
+            continue;
+        }
+        if skipped {
+            rendered.push_str("  ...\n");
+            skipped = false;
+        }
+        _ = match line {
+            Change::Same(text) => writeln!(rendered, "  {text}"),
+            Change::Removed(text) => writeln!(rendered, "- {text}"),
+            Change::Added(text) => writeln!(rendered, "+ {text}"),
+        };
+    }
+    if skipped {
+        rendered.push_str("  ...\n");
+    }
+    rendered
+}
+
+/// Asserts that the output of a tracker matches a snapshot stored in the
+/// directory `tests/snapshots` of the crate under test.
+///
+/// The first argument is an `OutputTracker` of either variant, the second
+/// argument is the name of the snapshot. The snapshot is stored in the file
+/// `tests/snapshots/<name>.snap`. See the module [`snapshot`] for how
+/// snapshots are created and updated.
+///
+/// # Example
+///
+/// ```no_run
+/// use output_tracker::assert_output_snapshot;
+/// use output_tracker::non_threadsafe::OutputSubject;
+///
+/// let subject = OutputSubject::new();
+/// let tracker = subject.create_tracker().unwrap();
+///
+/// for item in 1..=50 {
+///     subject.emit(item).unwrap();
+/// }
+///
+/// assert_output_snapshot!(tracker, "fifty_numbers");
+/// ```
+///
+/// # Panics
+///
+/// Panics if the output of the tracker can not be read or if it does not
+/// match the snapshot.
+///
+/// [`snapshot`]: crate::snapshot
+#[macro_export]
+macro_rules! assert_output_snapshot {
+    ($tracker:expr, $name:expr $(,)?) => {{
+        let output = $tracker
+            .output()
+            .unwrap_or_else(|err| ::std::panic!("could not read output of tracker: {err}"));
+        $crate::snapshot::assert_output_snapshot(
+            &output,
+            ::std::path::Path::new(::std::env!("CARGO_MANIFEST_DIR"))
+                .join("tests")
+                .join("snapshots"),
+            $name,
+        );
+    }};
+}
+
+#[cfg(test)]
+mod tests;
@@ -0,0 +1,135 @@
+use super::*;
+use asserting::prelude::*;
+use std::process;
+
+fn snapshot_dir(test_name: &str) -> PathBuf {
+    let dir = env::temp_dir().join(format!(
+        "output-tracker-snapshot-test-{}-{test_name}",
+        process::id()
+    ));
+    _ = fs::remove_dir_all(&dir);
+    dir
+}
+
+#[test]
+fn a_missing_snapshot_is_reported_and_the_actual_output_is_written_for_review() {
+    let dir = snapshot_dir("missing");
+    let snapshot_file = dir.join("numbers.snap");
+
+    let verification = verify_snapshot(&[1, 2, 3], &snapshot_file, false).ok();
+
+    assert_that!(verification).is_equal_to(Some(Verification::Missing {
+        new_file: dir.join("numbers.snap.new"),
+    }));
+    assert_that!(fs::read_to_string(dir.join("numbers.snap.new")).ok())
+        .is_equal_to(Some("[\n    1,\n    2,\n    3,\n]\n".to_string()));
+    assert_that!(snapshot_file.exists()).is_false();
+
+    _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn a_snapshot_matching_the_output_is_accepted_and_a_stale_new_file_is_removed() {
+    let dir = snapshot_dir("matched");
+    let snapshot_file = dir.join("words.snap");
+    fs::create_dir_all(&dir).unwrap_or_else(|err| panic!("could not create directory: {err}"));
+    fs::write(
+        &snapshot_file,
+        "[\r\n    \"alpha\",\r\n    \"beta\",\r\n]\r\n",
+    )
+    .unwrap_or_else(|err| panic!("could not write snapshot: {err}"));
+    fs::write(dir.join("words.snap.new"), "stale")
+        .unwrap_or_else(|err| panic!("could not write new snapshot: {err}"));
+
+    let verification = verify_snapshot(&["alpha", "beta"], &snapshot_file, false).ok();
+
+    assert_that!(verification).is_equal_to(Some(Verification::Matched));
+    assert_that!(dir.join("words.snap.new").exists()).is_false();
+
+    _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn a_snapshot_not_matching_the_output_is_reported_with_a_diff() {
+    let dir = snapshot_dir("mismatched");
+    let snapshot_file = dir.join("numbers.snap");
+    fs::create_dir_all(&dir).unwrap_or_else(|err| panic!("could not create directory: {err}"));
+    fs::write(&snapshot_file, "[\n    1,\n    2,\n    3,\n]\n")
+        .unwrap_or_else(|err| panic!("could not write snapshot: {err}"));
+
+    let verification = verify_snapshot(&[1, 4, 3], &snapshot_file, false).ok();
+
+    assert_that!(verification).is_equal_to(Some(Verification::Mismatched {
+        diff: "--- snapshot\n+++ actual\n  [\n      1,\n-     2,\n+     4,\n      3,\n  ]\n"
+            .to_string(),
+        new_file: dir.join("numbers.snap.new"),
+    }));
+    assert_that!(fs::read_to_string(&snapshot_file).ok())
+        .is_equal_to(Some("[\n    1,\n    2,\n    3,\n]\n".to_string()));
+
+    _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn in_update_mode_the_snapshot_is_overwritten_with_the_actual_output() {
+    let dir = snapshot_dir("updated");
+    let snapshot_file = dir.join("nested").join("numbers.snap");
+
+    let verification = verify_snapshot(&[7, 8], &snapshot_file, true).ok();
+
+    assert_that!(verification).is_equal_to(Some(Verification::Updated));
+    assert_that!(fs::read_to_string(&snapshot_file).ok())
+        .is_equal_to(Some("[\n    7,\n    8,\n]\n".to_string()));
+    assert_that!(verify_snapshot(&[7, 8], &snapshot_file, false).ok())
+        .is_equal_to(Some(Verification::Matched));
+
+    _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn a_diff_of_long_texts_shows_only_the_changed_lines_with_some_context() {
+    let expected = (1..=20).fold(String::new(), |text, n| text + &n.to_string() + "\n");
+    let actual = expected.replace("10\n", "ten\n");
+
+    let diff = diff_lines(&expected, &actual);
+
+    assert_that!(diff).is_equal_to(
+        "--- snapshot\n+++ actual\n  ...\n  8\n  9\n- 10\n+ ten\n  11\n  12\n  ...\n".to_string(),
+    );
+}
+
+#[test]
+fn a_large_snapshot_not_matching_the_output_in_one_item_is_reported_with_a_short_diff() {
+    let dir = snapshot_dir("large-mismatched");
+    let snapshot_file = dir.join("numbers.snap");
+    let expected = (0..100_000).collect::<Vec<_>>();
+    let mut actual = expected.clone();
+    actual[50_000] = -1;
+    assert_that!(verify_snapshot(&expected, &snapshot_file, true).ok())
+        .is_equal_to(Some(Verification::Updated));
+
+    let verification = verify_snapshot(&actual, &snapshot_file, false).ok();
+
+    assert_that!(verification).is_equal_to(Some(Verification::Mismatched {
+        diff: "--- snapshot\n+++ actual\n  ...\n      49998,\n      49999,\n-     50000,\n+     -1,\n      50001,\n      50002,\n  ...\n"
+            .to_string(),
+        new_file: dir.join("numbers.snap.new"),
+    }));
+
+    _ = fs::remove_dir_all(dir);
+}
+
+#[test]
+fn a_large_snapshot_differing_in_too_many_lines_is_reported_as_removed_and_added_lines() {
+    let expected = (0..10_000).fold(String::new(), |text, n| text + &n.to_string() + "\n");
+    let actual = (0..10_000).fold(String::new(), |text, n| text + "x" + &n.to_string() + "\n");
+
+    let diff = diff_lines(&expected, &actual);
+
+    let lines = diff.lines().collect::<Vec<_>>();
+    assert_that!(lines.len()).is_equal_to(2 + 20_000);
+    assert_that!(lines[2]).is_equal_to("- 0");
+    assert_that!(lines[10_001]).is_equal_to("- 9999");
+    assert_that!(lines[10_002]).is_equal_to("+ x0");
+    assert_that!(lines[20_001]).is_equal_to("+ x9999");
+}
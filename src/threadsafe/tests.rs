@@ -4,6 +4,7 @@ use proptest::collection::vec;
 use proptest::prelude::*;
 use std::sync::{mpsc, RwLock};
 use std::thread;
+use std::time::Duration;
 
 #[test]
 fn an_output_tracker_can_be_created_from_a_default_subject() {
@@ -25,6 +26,1346 @@ fn a_new_output_tracker_has_no_items_recorded() {
     assert_that!(tracker.output()).ok().is_empty();
 }
 
+fn number_of_registered_trackers<M>(subject: &OutputSubject<M>) -> usize {
+    subject
+        .inner
+        .subject()
+        .unwrap_or_else(|err| panic!("could not access subject: {err}"))
+        .trackers()
+        .count()
+}
+
+#[test]
+fn a_dropped_output_tracker_is_removed_from_its_subject() {
+    let subject = OutputSubject::<String>::new();
+    let tracker1 = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker 1: {err}"));
+    let tracker2 = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker 2: {err}"));
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(2);
+
+    drop(tracker1);
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(1);
+
+    drop(tracker2);
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(0);
+}
+
+struct AuditLog {
+    entries: DetachedTracker<String>,
+}
+
+impl AuditLog {
+    fn attach_to(subject: &OutputSubject<String>) -> Self {
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+        Self {
+            entries: tracker.detach(),
+        }
+    }
+
+    fn reader(&self) -> DetachedTracker<String> {
+        self.entries.clone()
+    }
+}
+
+#[test]
+fn a_detached_output_tracker_keeps_tracking_after_the_handle_it_was_created_from_is_dropped() {
+    let subject = OutputSubject::<String>::new();
+    let reader = AuditLog::attach_to(&subject).reader();
+
+    subject
+        .emit("user logged in".into())
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(1);
+    assert_that!(reader.output())
+        .ok()
+        .contains_exactly(["user logged in".to_string()]);
+}
+
+#[test]
+fn a_detached_output_tracker_can_be_cleared_and_stopped() {
+    let subject = OutputSubject::<String>::new();
+    let reader = AuditLog::attach_to(&subject).reader();
+
+    subject
+        .emit("user logged in".into())
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    reader
+        .clear()
+        .unwrap_or_else(|err| panic!("failed to clear detached tracker: {err}"));
+    subject
+        .emit("user logged out".into())
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    reader
+        .stop()
+        .unwrap_or_else(|err| panic!("failed to stop detached tracker: {err}"));
+    subject
+        .emit("user logged in again".into())
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(0);
+    assert_that!(reader.output())
+        .ok()
+        .contains_exactly(["user logged out".to_string()]);
+}
+
+#[test]
+fn dropping_a_stopped_output_tracker_does_not_panic() {
+    let subject = OutputSubject::<String>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    tracker
+        .stop()
+        .unwrap_or_else(|err| panic!("failed to stop output tracker: {err}"));
+    drop(tracker);
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(0);
+}
+
+#[test]
+fn the_output_of_a_tracker_can_be_read_while_it_is_read_by_another_thread() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    for item in [1, 2, 3] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    let reading = tracker
+        .inner
+        .tracker()
+        .unwrap_or_else(|err| panic!("could not obtain read access to tracker: {err}"));
+
+    let output = thread::scope(|scope| {
+        scope
+            .spawn(|| tracker.output())
+            .join()
+            .unwrap_or_else(|err| panic!("reading thread panicked: {err:?}"))
+    });
+
+    drop(reading);
+
+    assert_that!(output).ok().contains_exactly([1, 2, 3]);
+}
+
+#[test]
+fn a_bounded_output_tracker_with_overflow_policy_error_rejects_items_when_full() {
+    let subject = OutputSubject::<i64>::new();
+    let bounded_tracker = subject
+        .create_tracker_with(TrackerConfig {
+            capacity: 2,
+            overflow: OverflowPolicy::Error,
+        })
+        .unwrap_or_else(|err| panic!("could not create bounded output tracker: {err}"));
+    let unbounded_tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create unbounded output tracker: {err}"));
+
+    assert_that!(subject.emit(1)).is_ok();
+    assert_that!(subject.emit(2)).is_ok();
+    assert_that!(subject.emit(3))
+        .err()
+        .has_display_string("the capacity of the tracker of 2 items is exceeded");
+
+    assert_that!(bounded_tracker.output())
+        .ok()
+        .contains_exactly([1, 2]);
+    assert_that!(bounded_tracker.dropped_count())
+        .ok()
+        .is_equal_to(1);
+    assert_that!(unbounded_tracker.output())
+        .ok()
+        .contains_exactly([1, 2, 3]);
+    assert_that!(unbounded_tracker.dropped_count())
+        .ok()
+        .is_equal_to(0);
+}
+
+#[test]
+fn clearing_a_bounded_output_tracker_resets_the_dropped_count() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker_with(TrackerConfig {
+            capacity: 1,
+            overflow: OverflowPolicy::DropNewest,
+        })
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    for item in [1, 2, 3] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+    assert_that!(tracker.dropped_count()).ok().is_equal_to(2);
+
+    tracker
+        .clear()
+        .unwrap_or_else(|err| panic!("failed to clear output tracker: {err}"));
+
+    assert_that!(tracker.dropped_count()).ok().is_equal_to(0);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct CloneOnlyEven(i64);
+
+impl Clone for CloneOnlyEven {
+    fn clone(&self) -> Self {
+        assert!(self.0 % 2 == 0, "odd value {} must not be cloned", self.0);
+        Self(self.0)
+    }
+}
+
+#[test]
+fn a_filtered_output_tracker_does_not_clone_data_not_matching_the_predicate() {
+    let subject = OutputSubject::<CloneOnlyEven>::new();
+    let tracker = subject
+        .create_filtered_tracker(|item| item.0 % 2 == 0)
+        .unwrap_or_else(|err| panic!("could not create filtered output tracker: {err}"));
+
+    for item in 1..=5 {
+        subject
+            .emit(CloneOnlyEven(item))
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    assert_that!(tracker.output())
+        .ok()
+        .contains_exactly([CloneOnlyEven(2), CloneOnlyEven(4)]);
+}
+
+#[derive(Debug, PartialEq, Eq)]
+struct Request {
+    id: u32,
+    path: String,
+}
+
+#[test]
+fn a_mapped_output_tracker_records_projected_data_of_a_subject_that_is_not_clone() {
+    let subject = OutputSubject::<Request>::new();
+    let tracker = subject
+        .create_mapped_tracker(|request| request.path.clone())
+        .unwrap_or_else(|err| panic!("could not create mapped output tracker: {err}"));
+
+    for (id, path) in [(1, "/todos"), (2, "/todos/1")] {
+        subject
+            .emit(Request {
+                id,
+                path: path.into(),
+            })
+            .unwrap_or_else(|err| panic!("could not emit request {id} on output subject: {err}"));
+    }
+
+    assert_that!(tracker.output())
+        .ok()
+        .contains_exactly(["/todos".to_string(), "/todos/1".to_string()]);
+}
+
+#[test]
+fn a_dropped_mapped_output_tracker_is_removed_from_its_subject() {
+    let subject = OutputSubject::<Request>::new();
+    let tracker = subject
+        .create_mapped_tracker(|request| request.id)
+        .unwrap_or_else(|err| panic!("could not create mapped output tracker: {err}"));
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(1);
+
+    drop(tracker);
+
+    assert_that!(number_of_registered_trackers(&subject)).is_equal_to(0);
+}
+
+#[test]
+fn draining_an_output_tracker_returns_all_items_and_removes_them() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    for item in [1, 2, 3] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    assert_that!(tracker.drain())
+        .ok()
+        .contains_exactly([1, 2, 3]);
+    assert_that!(tracker.output()).ok().is_empty();
+
+    subject
+        .emit(4)
+        .unwrap_or_else(|err| panic!("could not emit item 4 on output subject: {err}"));
+
+    assert_that!(tracker.drain()).ok().contains_exactly([4]);
+}
+
+#[test]
+fn a_cursor_returns_only_items_recorded_since_its_last_read() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+    let mut cursor = tracker.cursor();
+
+    for item in [1, 2] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    assert_that!(cursor.take_new())
+        .ok()
+        .contains_exactly([1, 2]);
+    assert_that!(cursor.take_new()).ok().is_empty();
+
+    subject
+        .emit(3)
+        .unwrap_or_else(|err| panic!("could not emit item 3 on output subject: {err}"));
+
+    assert_that!(cursor.take_new()).ok().contains_exactly([3]);
+    assert_that!(tracker.output())
+        .ok()
+        .contains_exactly([1, 2, 3]);
+}
+
+#[test]
+fn a_cursor_skips_items_removed_from_the_tracker_before_they_were_read() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker_with(TrackerConfig {
+            capacity: 2,
+            overflow: OverflowPolicy::DropOldest,
+        })
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+    let mut cursor = tracker.cursor();
+
+    subject
+        .emit(1)
+        .unwrap_or_else(|err| panic!("could not emit item 1 on output subject: {err}"));
+    assert_that!(cursor.take_new()).ok().contains_exactly([1]);
+
+    for item in [2, 3, 4] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+    assert_that!(cursor.take_new())
+        .ok()
+        .contains_exactly([3, 4]);
+
+    subject
+        .emit(5)
+        .unwrap_or_else(|err| panic!("could not emit item 5 on output subject: {err}"));
+    tracker
+        .clear()
+        .unwrap_or_else(|err| panic!("failed to clear output tracker: {err}"));
+    subject
+        .emit(6)
+        .unwrap_or_else(|err| panic!("could not emit item 6 on output subject: {err}"));
+
+    assert_that!(cursor.take_new()).ok().contains_exactly([6]);
+}
+
+#[test]
+fn waiting_for_a_number_of_items_returns_the_output_once_enough_items_are_tracked() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    let worker = thread::spawn(move || {
+        for item in [1, 2, 3] {
+            thread::sleep(Duration::from_millis(5));
+            subject.emit(item)?;
+        }
+        Ok::<_, Error>(())
+    });
+
+    let output = tracker.wait_for_count(3, Duration::from_secs(10));
+
+    let emitted = worker
+        .join()
+        .unwrap_or_else(|_| panic!("worker thread panicked"));
+    assert_that!(emitted).is_ok();
+    assert_that!(output).ok().contains_exactly([1, 2, 3]);
+}
+
+#[test]
+fn waiting_until_a_predicate_is_satisfied_returns_the_output_once_it_matches() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    let worker = thread::spawn(move || {
+        for item in [1, 2, 3, 4] {
+            subject.emit(item)?;
+        }
+        Ok::<_, Error>(())
+    });
+
+    let output = tracker.wait_until(|output| output.contains(&4), Duration::from_secs(10));
+
+    let emitted = worker
+        .join()
+        .unwrap_or_else(|_| panic!("worker thread panicked"));
+    assert_that!(emitted).is_ok();
+    assert_that!(output).ok().contains_exactly([1, 2, 3, 4]);
+}
+
+#[test]
+fn waiting_for_more_items_than_are_tracked_times_out() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    subject
+        .emit(1)
+        .unwrap_or_else(|err| panic!("could not emit item 1 on output subject: {err}"));
+
+    let output = tracker.wait_for_count(2, Duration::from_millis(20));
+
+    assert_that!(output)
+        .err()
+        .has_display_string("timed out waiting for the tracked data to satisfy the condition");
+}
+
+#[test]
+fn a_recording_tracker_records_the_emitted_data_with_increasing_sequence_numbers() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+    for item in [1, 2, 3] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    let records = tracker
+        .output()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"));
+
+    assert_that!(records
+        .iter()
+        .map(Record::data)
+        .copied()
+        .collect::<Vec<_>>())
+    .contains_exactly([1, 2, 3]);
+    assert_that!(records
+        .windows(2)
+        .all(|pair| pair[0].sequence() < pair[1].sequence()
+            && pair[0].timestamp() <= pair[1].timestamp()))
+    .is_true();
+}
+
+#[test]
+fn records_of_different_subjects_are_ordered_by_the_time_they_were_emitted() {
+    let repository = OutputSubject::<&str>::new();
+    let mailer = OutputSubject::<String>::new();
+    let inserts = repository
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+    let emails = mailer
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+    repository
+        .emit("insert user")
+        .unwrap_or_else(|err| panic!("could not emit insert on repository: {err}"));
+    mailer
+        .emit("send welcome email".into())
+        .unwrap_or_else(|err| panic!("could not emit email on mailer: {err}"));
+
+    let insert = inserts
+        .output()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"))
+        .remove(0);
+    let email = emails
+        .output()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"))
+        .remove(0);
+
+    assert_that!(insert.sequence()).is_less_than(email.sequence());
+    assert_that!(insert.into_data()).is_equal_to("insert user");
+    assert_that!(email.into_data()).is_equal_to("send welcome email".to_string());
+}
+
+#[test]
+fn several_recording_trackers_of_one_subject_record_each_emission_with_the_same_sequence_number() {
+    let subject = OutputSubject::<i64>::new();
+    let first = subject
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+    let second = subject
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+    for item in [1, 2, 3] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    let first = first
+        .output()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"));
+    let second = second
+        .output()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"));
+
+    assert_that!(first.iter().map(Record::sequence).collect::<Vec<_>>())
+        .is_equal_to(second.iter().map(Record::sequence).collect::<Vec<_>>());
+    assert_that!(first.iter().map(Record::timestamp).collect::<Vec<_>>())
+        .is_equal_to(second.iter().map(Record::timestamp).collect::<Vec<_>>());
+    assert_that!(first
+        .windows(2)
+        .all(|pair| pair[0].sequence() + 1 == pair[1].sequence()))
+    .is_true();
+}
+
+#[test]
+fn recording_trackers_of_one_subject_track_concurrent_emissions_in_the_order_of_their_sequence_numbers(
+) {
+    let subject = OutputSubject::<usize>::new();
+    let trackers = (0..3)
+        .map(|_| {
+            subject
+                .create_recording_tracker()
+                .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"))
+        })
+        .collect::<Vec<_>>();
+
+    let emitters = (0..4)
+        .map(|thread_number| {
+            let emitter = subject.clone();
+            thread::spawn(move || {
+                for item in 0..250 {
+                    emitter.emit(thread_number * 1000 + item)?;
+                }
+                Ok::<_, Error>(())
+            })
+        })
+        .collect::<Vec<_>>();
+    for worker in emitters {
+        let emitted = worker
+            .join()
+            .unwrap_or_else(|_| panic!("worker thread panicked"));
+        assert_that!(emitted).is_ok();
+    }
+
+    let outputs = trackers
+        .iter()
+        .map(|tracker| {
+            tracker
+                .output()
+                .unwrap_or_else(|err| panic!("could not read tracker output: {err}"))
+                .into_iter()
+                .map(|record| (record.sequence(), record.into_data()))
+                .collect::<Vec<_>>()
+        })
+        .collect::<Vec<_>>();
+
+    for output in &outputs {
+        assert_that!(output.len()).is_equal_to(1000);
+        assert_that!(output.windows(2).all(|pair| pair[0].0 < pair[1].0)).is_true();
+        assert_that!(output).is_equal_to(&outputs[0]);
+    }
+}
+
+#[test]
+fn a_record_holds_the_id_and_the_name_of_the_emitting_thread() {
+    let subject = OutputSubject::<&str>::new();
+    let tracker = subject
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+    let emitter = subject.clone();
+    let worker = thread::Builder::new()
+        .name("worker".into())
+        .spawn(move || {
+            emitter
+                .emit("from worker")
+                .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+            thread::current().id()
+        })
+        .unwrap_or_else(|err| panic!("could not spawn worker thread: {err}"));
+    let worker_id = worker
+        .join()
+        .unwrap_or_else(|_| panic!("worker thread panicked"));
+    subject
+        .emit("from test")
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+
+    let records = tracker
+        .output()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"));
+
+    assert_that!(records[0].thread_id()).is_equal_to(worker_id);
+    assert_that!(records[0].thread_name()).is_equal_to(Some("worker"));
+    assert_that!(records[1].thread_id()).is_equal_to(thread::current().id());
+    assert_that!(records[1].thread_name()).is_equal_to(thread::current().name());
+}
+
+#[test]
+fn output_by_thread_groups_the_records_by_the_emitting_thread_in_order_of_their_first_record() {
+    let subject = OutputSubject::<i32>::new();
+    let tracker = subject
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+    subject
+        .emit(1)
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    let emitter = subject.clone();
+    thread::spawn(move || {
+        for item in [10, 11] {
+            emitter.emit(item).unwrap_or_else(|err| {
+                panic!("could not emit item {item} on output subject: {err}")
+            });
+        }
+    })
+    .join()
+    .unwrap_or_else(|_| panic!("worker thread panicked"));
+    subject
+        .emit(2)
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+
+    let by_thread = tracker
+        .output_by_thread()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"));
+
+    assert_that!(by_thread
+        .iter()
+        .map(|group| group.output().to_vec())
+        .collect::<Vec<_>>())
+    .contains_exactly([vec![1, 2], vec![10, 11]]);
+    assert_that!(by_thread[0].thread_id()).is_equal_to(thread::current().id());
+    assert_that!(by_thread[1].thread_id()).is_not_equal_to(thread::current().id());
+}
+
+#[test]
+fn output_for_returns_the_data_emitted_within_a_scope_with_the_given_correlation_id() {
+    let subject = OutputSubject::<&str>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    subject
+        .emit("startup")
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    {
+        let _scope = TrackingScope::enter("request-1");
+        subject
+            .emit("load user")
+            .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+        {
+            let _nested = TrackingScope::enter("request-2");
+            subject
+                .emit("load order")
+                .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+        }
+        subject
+            .emit("render page")
+            .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    }
+
+    assert_that!(tracker.output_for("request-1"))
+        .ok()
+        .contains_exactly(["load user", "render page"]);
+    assert_that!(tracker.output_for("request-2"))
+        .ok()
+        .contains_exactly(["load order"]);
+    assert_that!(tracker.output_for("request-3"))
+        .ok()
+        .is_empty();
+    assert_that!(tracker.output()).ok().contains_exactly([
+        "startup",
+        "load user",
+        "load order",
+        "render page",
+    ]);
+}
+
+#[test]
+fn output_for_only_returns_data_that_has_not_been_dropped_from_the_tracker() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker_with(TrackerConfig {
+            capacity: 2,
+            overflow: OverflowPolicy::DropOldest,
+        })
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    for (correlation_id, item) in [("odd", 1), ("even", 2), ("odd", 3), ("even", 4)] {
+        let _scope = TrackingScope::enter(correlation_id);
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    assert_that!(tracker.output_for("odd"))
+        .ok()
+        .contains_exactly([3]);
+    assert_that!(tracker.output_for("even"))
+        .ok()
+        .contains_exactly([4]);
+
+    _ = tracker
+        .drain()
+        .unwrap_or_else(|err| panic!("failed to drain tracker: {err}"));
+
+    assert_that!(tracker.output_for("odd")).ok().is_empty();
+}
+
+#[test]
+fn a_record_holds_the_correlation_id_of_the_scope_the_data_was_emitted_in() {
+    let subject = OutputSubject::<i32>::new();
+    let tracker = subject
+        .create_recording_tracker()
+        .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+    subject
+        .emit(1)
+        .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    {
+        let _scope = TrackingScope::enter(String::from("request-7"));
+        subject
+            .emit(2)
+            .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    }
+
+    let records = tracker
+        .output()
+        .unwrap_or_else(|err| panic!("could not read tracker output: {err}"));
+
+    assert_that!(records[0].correlation_id()).is_none();
+    assert_that!(records[1].correlation_id()).is_equal_to(Some("request-7"));
+}
+
+#[test]
+fn leaving_a_nested_scope_restores_the_correlation_id_of_the_outer_scope() {
+    assert_that!(TrackingScope::current()).is_none();
+
+    let outer = TrackingScope::enter("outer");
+    {
+        let _inner = TrackingScope::enter("inner");
+
+        assert_that!(TrackingScope::current()).is_equal_to(Some("inner".to_string()));
+    }
+    assert_that!(TrackingScope::current()).is_equal_to(Some("outer".to_string()));
+
+    drop(outer);
+
+    assert_that!(TrackingScope::current()).is_none();
+}
+
+#[test]
+fn the_correlation_id_of_a_scope_is_only_attached_to_data_emitted_on_the_same_thread() {
+    let subject = OutputSubject::<&str>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    let handlers = ["request-1", "request-2"].map(|correlation_id| {
+        let subject = subject.clone();
+        thread::spawn(move || {
+            let _scope = TrackingScope::enter(correlation_id);
+            for step in ["parse", "handle", "respond"] {
+                subject
+                    .emit(step)
+                    .unwrap_or_else(|err| panic!("could not emit {step} on output subject: {err}"));
+            }
+        })
+    });
+    let _scope = TrackingScope::enter("main");
+    for handler in handlers {
+        handler
+            .join()
+            .unwrap_or_else(|_| panic!("handler thread panicked"));
+    }
+
+    for correlation_id in ["request-1", "request-2"] {
+        assert_that!(tracker.output_for(correlation_id))
+            .ok()
+            .contains_exactly(["parse", "handle", "respond"]);
+    }
+    assert_that!(tracker.output_for("main")).ok().is_empty();
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Event {
+    Inserted(u64),
+    EmailSent(String),
+}
+
+#[test]
+fn a_timeline_records_the_data_of_several_subjects_in_the_order_of_emission() {
+    let repository = OutputSubject::<u64>::new();
+    let mailer = OutputSubject::<String>::new();
+    let mut timeline = Timeline::new();
+    timeline
+        .attach(&repository, |id| Event::Inserted(*id))
+        .unwrap_or_else(|err| panic!("could not attach timeline to repository: {err}"));
+    timeline
+        .attach(&mailer, |email| Event::EmailSent(email.clone()))
+        .unwrap_or_else(|err| panic!("could not attach timeline to mailer: {err}"));
+
+    repository
+        .emit(1)
+        .unwrap_or_else(|err| panic!("could not emit item 1 on repository: {err}"));
+    mailer
+        .emit("welcome".into())
+        .unwrap_or_else(|err| panic!("could not emit email on mailer: {err}"));
+    repository
+        .emit(2)
+        .unwrap_or_else(|err| panic!("could not emit item 2 on repository: {err}"));
+
+    assert_that!(timeline.output()).ok().contains_exactly([
+        Event::Inserted(1),
+        Event::EmailSent("welcome".into()),
+        Event::Inserted(2),
+    ]);
+}
+
+#[test]
+fn a_dropped_timeline_is_removed_from_all_attached_subjects() {
+    let repository = OutputSubject::<u64>::new();
+    let mailer = OutputSubject::<String>::new();
+    let mut timeline = Timeline::new();
+    timeline
+        .attach(&repository, |id| Event::Inserted(*id))
+        .unwrap_or_else(|err| panic!("could not attach timeline to repository: {err}"));
+    timeline
+        .attach(&mailer, |email| Event::EmailSent(email.clone()))
+        .unwrap_or_else(|err| panic!("could not attach timeline to mailer: {err}"));
+
+    drop(timeline);
+
+    assert_that!(number_of_registered_trackers(&repository)).is_equal_to(0);
+    assert_that!(number_of_registered_trackers(&mailer)).is_equal_to(0);
+}
+
+#[test]
+fn a_stopped_timeline_no_longer_records_data_of_any_subject() {
+    let repository = OutputSubject::<u64>::new();
+    let mailer = OutputSubject::<String>::new();
+    let mut timeline = Timeline::new();
+    timeline
+        .attach(&repository, |id| Event::Inserted(*id))
+        .unwrap_or_else(|err| panic!("could not attach timeline to repository: {err}"));
+    timeline
+        .attach(&mailer, |email| Event::EmailSent(email.clone()))
+        .unwrap_or_else(|err| panic!("could not attach timeline to mailer: {err}"));
+
+    repository
+        .emit(1)
+        .unwrap_or_else(|err| panic!("could not emit item 1 on repository: {err}"));
+    timeline
+        .stop()
+        .unwrap_or_else(|err| panic!("could not stop timeline: {err}"));
+    repository
+        .emit(2)
+        .unwrap_or_else(|err| panic!("could not emit item 2 on repository: {err}"));
+    mailer
+        .emit("welcome".into())
+        .unwrap_or_else(|err| panic!("could not emit email on mailer: {err}"));
+
+    assert_that!(timeline.output())
+        .ok()
+        .contains_exactly([Event::Inserted(1)]);
+}
+
+#[test]
+fn configurable_responses_configured_to_always_return_a_response_return_it_every_time() {
+    let responses = ConfigurableResponses::always("NulledClock", 42);
+
+    for _ in 0..3 {
+        assert_that!(responses.next()).ok().is_equal_to(42);
+    }
+}
+
+#[test]
+fn configurable_responses_return_a_sequence_of_responses_in_order() {
+    let responses = ConfigurableResponses::sequence("NulledDb", ["first", "second"]);
+
+    assert_that!(responses.next()).ok().is_equal_to("first");
+    assert_that!(responses.next()).ok().is_equal_to("second");
+}
+
+#[test]
+fn configurable_responses_fail_naming_the_adapter_when_all_responses_are_consumed() {
+    let responses = ConfigurableResponses::sequence("NulledDb", [1]);
+
+    assert_that!(responses.next()).ok().is_equal_to(1);
+    assert_that!(responses.next())
+        .err()
+        .has_display_string("no more responses configured for NulledDb");
+}
+
+#[test]
+fn clones_of_configurable_responses_share_the_same_responses() {
+    let responses = ConfigurableResponses::sequence("NulledDb", [1, 2]);
+    let cloned_responses = responses.clone();
+
+    assert_that!(cloned_responses.next()).ok().is_equal_to(1);
+    assert_that!(responses.next()).ok().is_equal_to(2);
+    assert_that!(cloned_responses.next()).is_err();
+}
+
+#[test]
+fn the_unordered_output_of_a_tracker_is_the_same_multiset_regardless_of_the_order() {
+    let subject = OutputSubject::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    for item in ["beta", "alpha", "beta"] {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    let output = tracker
+        .output_unordered()
+        .unwrap_or_else(|err| panic!("could not read output of tracker: {err}"));
+
+    assert_that!(output.is_same_multiset(["alpha", "beta", "beta"])).is_true();
+    assert_that!(output.is_same_multiset(["alpha", "beta"])).is_false();
+    output.assert_same_multiset(["beta", "beta", "alpha"]);
+}
+
+#[test]
+fn the_output_of_items_implementing_ord_but_not_hash_can_be_compared_unordered() {
+    #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+    struct Version(u32, u32);
+
+    let subject = OutputSubject::<Version>::new();
+    let tracker = subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create tracker: {err}"));
+
+    for version in [Version(1, 2), Version(0, 9), Version(1, 2)] {
+        subject
+            .emit(version)
+            .unwrap_or_else(|err| panic!("could not emit item on output subject: {err}"));
+    }
+
+    let output = tracker
+        .output_unordered_by_ord()
+        .unwrap_or_else(|err| panic!("could not read output of tracker: {err}"));
+
+    assert_that!(output.is_same_multiset([Version(1, 2), Version(1, 2), Version(0, 9)])).is_true();
+    assert_that!(output.is_same_multiset([Version(0, 9), Version(1, 2)])).is_false();
+    let difference = output.difference([Version(0, 9), Version(2, 0)]);
+    assert_that!(difference.missing()).is_equal_to(&[Version(2, 0)][..]);
+    assert_that!(difference.extra()).is_equal_to(&[Version(1, 2), Version(1, 2)][..]);
+}
+
+#[test]
+fn the_difference_of_unordered_output_lists_missing_and_extra_items_separately() {
+    let hashed = UnorderedOutput::hashed([1, 2, 2, 3, 3]);
+    let ordered = UnorderedOutput::ordered([1, 2, 2, 3, 3]);
+
+    for difference in [
+        hashed.difference([3, 4, 2, 4, 1]),
+        ordered.difference([3, 4, 2, 4, 1]),
+    ] {
+        assert_that!(difference.missing()).is_equal_to(&[4, 4][..]);
+        assert_that!(difference.extra()).is_equal_to(&[2, 3][..]);
+        assert_that!(difference.is_empty()).is_false();
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "tracked items are not the same multiset as the expected items\n  missing items: \
+                [\"gamma\"]\n    extra items: [\"alpha\"]"
+)]
+fn asserting_the_same_multiset_fails_listing_missing_and_extra_items() {
+    let output = UnorderedOutput::hashed(["alpha", "beta"]);
+
+    output.assert_same_multiset(["beta", "gamma"]);
+}
+
+#[test]
+fn a_snapshot_holds_the_tracked_data_and_the_number_of_dropped_items() {
+    let subject = OutputSubject::<i64>::new();
+    let tracker = subject
+        .create_tracker_with(TrackerConfig {
+            capacity: 2,
+            overflow: OverflowPolicy::DropOldest,
+        })
+        .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+    for item in 1..=5 {
+        subject
+            .emit(item)
+            .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+    }
+
+    assert_that!(tracker.snapshot())
+        .ok()
+        .is_equal_to(TrackerSnapshot {
+            output: vec![4, 5],
+            dropped_count: 3,
+        });
+}
+
+#[cfg(feature = "async")]
+mod async_wait {
+    use super::*;
+    use futures::executor::block_on;
+
+    #[test]
+    fn awaiting_a_number_of_items_resolves_once_enough_items_are_tracked() {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        let worker = thread::spawn(move || {
+            for item in [1, 2, 3] {
+                thread::sleep(Duration::from_millis(5));
+                subject.emit(item)?;
+            }
+            Ok::<_, Error>(())
+        });
+
+        let output = block_on(tracker.wait_for_count_async(3));
+
+        let emitted = worker
+            .join()
+            .unwrap_or_else(|_| panic!("worker thread panicked"));
+        assert_that!(emitted).is_ok();
+        assert_that!(output).ok().contains_exactly([1, 2, 3]);
+    }
+
+    #[test]
+    fn awaiting_a_predicate_resolves_immediately_if_it_is_already_satisfied() {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        subject
+            .emit(42)
+            .unwrap_or_else(|err| panic!("could not emit item 42 on output subject: {err}"));
+
+        let output = block_on(tracker.wait_until_async(|output| output == [42]));
+
+        assert_that!(output).ok().contains_exactly([42]);
+    }
+}
+
+#[cfg(feature = "stream")]
+mod stream {
+    use super::*;
+    use futures::executor::block_on;
+    use futures::task::noop_waker_ref;
+    use futures::StreamExt;
+    use std::task::{Context, Poll};
+
+    #[test]
+    fn a_stream_yields_the_items_emitted_after_it_has_been_subscribed() {
+        let subject = OutputSubject::<i64>::new();
+        subject
+            .emit(0)
+            .unwrap_or_else(|err| panic!("could not emit item 0 on output subject: {err}"));
+        let stream = subject
+            .subscribe_stream()
+            .unwrap_or_else(|err| panic!("could not subscribe stream: {err}"));
+
+        for item in [1, 2, 3] {
+            subject.emit(item).unwrap_or_else(|err| {
+                panic!("could not emit item {item} on output subject: {err}")
+            });
+        }
+        drop(subject);
+
+        let output = block_on(stream.collect::<Vec<_>>());
+
+        assert_that!(output).contains_exactly([1, 2, 3]);
+    }
+
+    #[test]
+    fn awaiting_the_next_item_of_a_stream_yields_the_next_emitted_item() {
+        let subject = OutputSubject::<i64>::new();
+        let mut stream = subject
+            .subscribe_stream()
+            .unwrap_or_else(|err| panic!("could not subscribe stream: {err}"));
+
+        for item in [1, 2] {
+            subject.emit(item).unwrap_or_else(|err| {
+                panic!("could not emit item {item} on output subject: {err}")
+            });
+        }
+
+        assert_that!(block_on(stream.next())).is_equal_to(Some(1));
+        assert_that!(block_on(stream.next())).is_equal_to(Some(2));
+    }
+
+    #[test]
+    fn a_stream_is_pending_while_no_item_has_been_emitted() {
+        let subject = OutputSubject::<i64>::new();
+        let mut stream = subject
+            .subscribe_stream()
+            .unwrap_or_else(|err| panic!("could not subscribe stream: {err}"));
+        let mut context = Context::from_waker(noop_waker_ref());
+
+        assert_that!(stream.poll_next_unpin(&mut context)).is_equal_to(Poll::Pending);
+    }
+
+    #[test]
+    fn a_stream_ends_when_all_clones_of_its_subject_have_been_dropped() {
+        let subject = OutputSubject::<i64>::new();
+        let cloned_subject = subject.clone();
+        let mut stream = subject
+            .subscribe_stream()
+            .unwrap_or_else(|err| panic!("could not subscribe stream: {err}"));
+        let mut context = Context::from_waker(noop_waker_ref());
+
+        drop(subject);
+        cloned_subject
+            .emit(1)
+            .unwrap_or_else(|err| panic!("could not emit item 1 on output subject: {err}"));
+
+        assert_that!(stream.poll_next_unpin(&mut context)).is_equal_to(Poll::Ready(Some(1)));
+        assert_that!(stream.poll_next_unpin(&mut context)).is_equal_to(Poll::Pending);
+
+        drop(cloned_subject);
+
+        assert_that!(stream.poll_next_unpin(&mut context)).is_equal_to(Poll::Ready(None));
+    }
+
+    #[test]
+    fn a_dropped_stream_is_removed_from_its_subject() {
+        let subject = OutputSubject::<i64>::new();
+        let stream = subject
+            .subscribe_stream()
+            .unwrap_or_else(|err| panic!("could not subscribe stream: {err}"));
+
+        assert_that!(number_of_registered_trackers(&subject)).is_equal_to(1);
+
+        drop(stream);
+
+        assert_that!(number_of_registered_trackers(&subject)).is_equal_to(0);
+    }
+
+    #[test]
+    fn a_stream_yields_items_emitted_from_another_thread() {
+        let subject = OutputSubject::<i64>::new();
+        let stream = subject
+            .subscribe_stream()
+            .unwrap_or_else(|err| panic!("could not subscribe stream: {err}"));
+
+        let worker = thread::spawn(move || {
+            for item in [1, 2, 3] {
+                thread::sleep(Duration::from_millis(5));
+                subject.emit(item)?;
+            }
+            Ok::<_, Error>(())
+        });
+
+        let output = block_on(stream.collect::<Vec<_>>());
+
+        let emitted = worker
+            .join()
+            .unwrap_or_else(|_| panic!("worker thread panicked"));
+        assert_that!(emitted).is_ok();
+        assert_that!(output).contains_exactly([1, 2, 3]);
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_export {
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+    struct Event {
+        id: u64,
+        name: String,
+    }
+
+    fn event(id: u64, name: &str) -> Event {
+        Event {
+            id,
+            name: name.into(),
+        }
+    }
+
+    fn subject_with_tracked_events() -> (OutputSubject<Event>, OutputTracker<Event>) {
+        let subject = OutputSubject::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+        for event in [event(1, "created"), event(2, "deleted")] {
+            subject
+                .emit(event)
+                .unwrap_or_else(|err| panic!("could not emit event: {err}"));
+        }
+        (subject, tracker)
+    }
+
+    #[test]
+    fn the_output_of_a_tracker_can_be_serialized_as_json_array() {
+        let (_subject, tracker) = subject_with_tracked_events();
+
+        assert_that!(tracker.output_json())
+            .ok()
+            .is_equal_to(r#"[{"id":1,"name":"created"},{"id":2,"name":"deleted"}]"#.to_string());
+    }
+
+    #[test]
+    fn the_output_of_a_tracker_can_be_exported_as_json_lines() {
+        let (_subject, tracker) = subject_with_tracked_events();
+        let mut exported = Vec::new();
+
+        tracker
+            .export_jsonl(&mut exported)
+            .unwrap_or_else(|err| panic!("could not export tracker output: {err}"));
+
+        assert_that!(String::from_utf8(exported).ok()).is_equal_to(Some(
+            "{\"id\":1,\"name\":\"created\"}\n{\"id\":2,\"name\":\"deleted\"}\n".to_string(),
+        ));
+    }
+
+    #[test]
+    fn a_snapshot_of_a_tracker_can_be_serialized_and_deserialized() {
+        let (_subject, tracker) = subject_with_tracked_events();
+        let snapshot = tracker
+            .snapshot()
+            .unwrap_or_else(|err| panic!("could not take snapshot of tracker: {err}"));
+
+        let json = serde_json::to_string(&snapshot)
+            .unwrap_or_else(|err| panic!("could not serialize snapshot: {err}"));
+        let deserialized = serde_json::from_str::<TrackerSnapshot<Event>>(&json).ok();
+
+        assert_that!(json).is_equal_to(
+            r#"{"output":[{"id":1,"name":"created"},{"id":2,"name":"deleted"}],"dropped_count":0}"#
+                .to_string(),
+        );
+        assert_that!(deserialized).is_equal_to(Some(snapshot));
+    }
+
+    #[test]
+    fn recorded_output_saved_to_a_file_is_replayed_as_configured_responses() {
+        let (_subject, tracker) = subject_with_tracked_events();
+        let recording_file = std::env::temp_dir().join(format!(
+            "output-tracker-recording-threadsafe-{}.jsonl",
+            std::process::id()
+        ));
+
+        tracker
+            .save_recording(&recording_file)
+            .unwrap_or_else(|err| panic!("could not save recording: {err}"));
+        let responses =
+            ConfigurableResponses::<Event>::load_recording("NulledEvents", &recording_file)
+                .unwrap_or_else(|err| panic!("could not load recording: {err}"));
+        _ = std::fs::remove_file(recording_file);
+
+        assert_that!(responses.next().ok()).is_equal_to(Some(event(1, "created")));
+        assert_that!(responses.next().ok()).is_equal_to(Some(event(2, "deleted")));
+        assert_that!(responses.next().map_err(|err| err.to_string())).is_equal_to(Err(
+            "no more responses configured for NulledEvents".to_string(),
+        ));
+    }
+
+    #[test]
+    fn replaying_a_recording_skips_empty_lines() {
+        let recording = "\n{\"id\":3,\"name\":\"renamed\"}\n\n";
+
+        let responses =
+            ConfigurableResponses::<Event>::replay("NulledEvents", recording.as_bytes())
+                .unwrap_or_else(|err| panic!("could not replay recording: {err}"));
+
+        assert_that!(responses.next().ok()).is_equal_to(Some(event(3, "renamed")));
+        assert_that!(responses.next().ok()).is_equal_to(None);
+    }
+
+    #[test]
+    fn replaying_a_malformed_recording_fails() {
+        let recording = "{\"id\":3,\"name\":\"renamed\"}\n{\"id\":\"four\"}\n";
+
+        let result = ConfigurableResponses::<Event>::replay("NulledEvents", recording.as_bytes());
+
+        assert_that!(matches!(result, Err(Error::ReplayFailed(_)))).is_true();
+    }
+
+    #[test]
+    fn loading_a_missing_recording_fails() {
+        let result = ConfigurableResponses::<Event>::load_recording(
+            "NulledEvents",
+            "this/recording/does/not/exist.jsonl",
+        );
+
+        assert_that!(matches!(result, Err(Error::Io(_)))).is_true();
+    }
+
+    #[test]
+    fn saving_a_recording_into_a_missing_directory_fails() {
+        let subject = OutputSubject::<Event>::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        let result = tracker.save_recording("this/directory/does/not/exist/recording.jsonl");
+
+        assert_that!(matches!(result, Err(Error::Io(_)))).is_true();
+    }
+
+    struct FailingIo;
+
+    impl Write for FailingIo {
+        fn write(&mut self, _buf: &[u8]) -> io::Result<usize> {
+            Err(io::Error::other("no space left on device"))
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    impl io::Read for FailingIo {
+        fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+            Err(io::Error::other("device not ready"))
+        }
+    }
+
+    #[test]
+    fn exporting_into_a_failing_writer_fails_with_an_io_error() {
+        let (_subject, tracker) = subject_with_tracked_events();
+
+        let result = tracker.export_jsonl(FailingIo);
+
+        assert_that!(matches!(result, Err(Error::Io(_)))).is_true();
+    }
+
+    #[test]
+    fn replaying_from_a_failing_reader_fails_with_an_io_error() {
+        let result =
+            ConfigurableResponses::<Event>::replay("NulledEvents", BufReader::new(FailingIo));
+
+        assert_that!(matches!(result, Err(Error::Io(_)))).is_true();
+    }
+}
+
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(1000))]
 
@@ -212,6 +1553,198 @@ proptest! {
     }
 }
 
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(256))]
+
+    #[test]
+    fn unordered_output_is_the_same_multiset_as_any_permutation_of_it(
+        items in vec(0..5_u8, 0..20).prop_flat_map(|items| (Just(items.clone()), Just(items).prop_shuffle())),
+    ) {
+        let (items, shuffled) = items;
+
+        prop_assert!(UnorderedOutput::hashed(items.clone()).is_same_multiset(shuffled.clone()));
+        prop_assert!(UnorderedOutput::ordered(items).is_same_multiset(shuffled));
+    }
+
+    #[test]
+    fn a_bounded_output_tracker_dropping_oldest_items_holds_the_last_items(
+        capacity in 0..=50_usize,
+        items in (0..=300_usize).prop_flat_map(|size| vec(any::<i64>(), size)),
+    ) {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_tracker_with(TrackerConfig { capacity, overflow: OverflowPolicy::DropOldest })
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        for item in &items {
+            subject.emit(*item)
+                .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+        }
+
+        let expected = &items[items.len().saturating_sub(capacity)..];
+        let output = tracker.output()
+            .unwrap_or_else(|err| panic!("failed to read tracker output: {err}"));
+        prop_assert_eq!(&output, expected);
+        let dropped_count = tracker.dropped_count()
+            .unwrap_or_else(|err| panic!("failed to read dropped count: {err}"));
+        prop_assert_eq!(dropped_count, items.len() - expected.len());
+    }
+
+    #[test]
+    fn a_bounded_output_tracker_dropping_newest_items_holds_the_first_items(
+        capacity in 0..=50_usize,
+        items in (0..=300_usize).prop_flat_map(|size| vec(any::<i64>(), size)),
+    ) {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_tracker_with(TrackerConfig { capacity, overflow: OverflowPolicy::DropNewest })
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        for item in &items {
+            subject.emit(*item)
+                .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+        }
+
+        let expected = &items[..capacity.min(items.len())];
+        let output = tracker.output()
+            .unwrap_or_else(|err| panic!("failed to read tracker output: {err}"));
+        prop_assert_eq!(&output, expected);
+        let dropped_count = tracker.dropped_count()
+            .unwrap_or_else(|err| panic!("failed to read dropped count: {err}"));
+        prop_assert_eq!(dropped_count, items.len() - expected.len());
+    }
+
+    #[test]
+    fn a_filtered_output_tracker_records_only_items_matching_the_predicate(
+        items in (0..=300_usize).prop_flat_map(|size| vec(any::<i64>(), size)),
+    ) {
+        let subject = OutputSubject::<i64>::new();
+        let filtered_tracker = subject
+            .create_filtered_tracker(|item| item % 3 == 0)
+            .unwrap_or_else(|err| panic!("could not create filtered output tracker: {err}"));
+        let unfiltered_tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create unfiltered output tracker: {err}"));
+
+        for item in &items {
+            subject.emit(*item)
+                .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+        }
+
+        let expected = items.iter().copied().filter(|item| item % 3 == 0).collect::<Vec<_>>();
+        let output = filtered_tracker.output()
+            .unwrap_or_else(|err| panic!("failed to read filtered tracker output: {err}"));
+        prop_assert_eq!(output, expected);
+        let output = unfiltered_tracker.output()
+            .unwrap_or_else(|err| panic!("failed to read unfiltered tracker output: {err}"));
+        prop_assert_eq!(output, items);
+    }
+
+    #[test]
+    fn a_mapped_output_tracker_records_the_projection_of_all_items_in_order(
+        items in (0..=300_usize).prop_flat_map(|size| vec(any::<i64>(), size)),
+    ) {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_mapped_tracker(ToString::to_string)
+            .unwrap_or_else(|err| panic!("could not create mapped output tracker: {err}"));
+
+        for item in &items {
+            subject.emit(*item)
+                .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+        }
+
+        let expected = items.iter().map(ToString::to_string).collect::<Vec<_>>();
+        let output = tracker.output()
+            .unwrap_or_else(|err| panic!("failed to read tracker output: {err}"));
+        prop_assert_eq!(output, expected);
+    }
+
+    #[test]
+    fn several_cursors_read_the_items_of_each_phase_independently(
+        phases in vec((0..=50_usize).prop_flat_map(|size| vec(any::<i64>(), size)), 0..=10),
+    ) {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+        let mut cursor1 = tracker.cursor();
+        let mut cursor2 = tracker.cursor();
+
+        for items in &phases {
+            for item in items {
+                subject.emit(*item)
+                    .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+            }
+
+            let new_items = cursor1.take_new()
+                .unwrap_or_else(|err| panic!("failed to read new items from cursor 1: {err}"));
+            prop_assert_eq!(&new_items, items);
+        }
+
+        let all_items = cursor2.take_new()
+            .unwrap_or_else(|err| panic!("failed to read new items from cursor 2: {err}"));
+        prop_assert_eq!(all_items, phases.concat());
+    }
+
+    #[test]
+    fn draining_an_output_tracker_after_each_phase_returns_the_items_of_this_phase(
+        phases in vec((0..=50_usize).prop_flat_map(|size| vec(any::<i64>(), size)), 0..=10),
+    ) {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        for items in &phases {
+            for item in items {
+                subject.emit(*item)
+                    .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+            }
+
+            let drained = tracker.drain()
+                .unwrap_or_else(|err| panic!("failed to drain tracker: {err}"));
+            prop_assert_eq!(&drained, items);
+        }
+    }
+
+    #[test]
+    fn the_sequence_numbers_of_records_reflect_the_order_of_emission_across_subjects(
+        emit_on_first in vec(any::<bool>(), 0..=100),
+    ) {
+        let first = OutputSubject::<usize>::new();
+        let second = OutputSubject::<usize>::new();
+        let first_tracker = first
+            .create_recording_tracker()
+            .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+        let second_tracker = second
+            .create_recording_tracker()
+            .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+        for (index, &on_first) in emit_on_first.iter().enumerate() {
+            let subject = if on_first { &first } else { &second };
+            subject
+                .emit(index)
+                .unwrap_or_else(|err| panic!("could not emit item {index} on output subject: {err}"));
+        }
+
+        let mut records = first_tracker
+            .output()
+            .unwrap_or_else(|err| panic!("could not read tracker output: {err}"));
+        records.extend(
+            second_tracker
+                .output()
+                .unwrap_or_else(|err| panic!("could not read tracker output: {err}")),
+        );
+        records.sort_by_key(Record::sequence);
+
+        prop_assert_eq!(
+            records.into_iter().map(Record::into_data).collect::<Vec<_>>(),
+            (0..emit_on_first.len()).collect::<Vec<_>>()
+        );
+    }
+}
+
 proptest! {
     #![proptest_config(ProptestConfig::with_cases(200))]
 
@@ -228,41 +1761,41 @@ proptest! {
         let items_thread1 = items[0..n1].to_owned();
         let thread1 = thread::spawn(move || {
             for item in &items_thread1 {
-                subject1.emit(*item)
-                    .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+                subject1.emit(*item)?;
             }
+            Ok::<_, Error>(())
         });
 
         let subject2 = subject.clone();
         let items_thread2 = items[n1..n1 + n2].to_owned();
         let thread2 = thread::spawn(move || {
             for item in &items_thread2 {
-                subject2.emit(*item)
-                    .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+                subject2.emit(*item)?;
             }
+            Ok::<_, Error>(())
         });
 
         let subject3 = subject;
         let items_thread3 = items[n1 + n2..].to_owned();
         let thread3 = thread::spawn(move || {
             for item in &items_thread3 {
-                subject3.emit(*item)
-                    .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+                subject3.emit(*item)?;
             }
+            Ok::<_, Error>(())
         });
 
-        thread1.join().unwrap_or_else(|err| panic!("thread 1 panicked: {err:?}"));
-        thread2.join().unwrap_or_else(|err| panic!("thread 2 panicked: {err:?}"));
-        thread3.join().unwrap_or_else(|err| panic!("thread 3 panicked: {err:?}"));
+        let waited = tracker.wait_for_count(items.len(), Duration::from_secs(10));
 
-        let mut output = tracker.output()
-            .unwrap_or_else(|err| panic!("failed to read tracker output: {err}"));
-        output.sort_unstable();
-
-        let mut items = items;
-        items.sort_unstable();
+        for (number, emitter) in [thread1, thread2, thread3].into_iter().enumerate() {
+            let emitted = emitter.join().unwrap_or_else(|err| panic!("thread {} panicked: {err:?}", number + 1));
+            prop_assert!(emitted.is_ok(), "thread {} failed to emit: {:?}", number + 1, emitted);
+        }
+        let output = UnorderedOutput::hashed(
+            waited.unwrap_or_else(|err| panic!("failed to wait for tracker output: {err}")),
+        );
 
-        prop_assert_eq!(&output, &items);
+        let difference = output.difference(items);
+        prop_assert!(difference.is_empty(), "{}", difference);
     }
 
     #[test]
@@ -412,3 +1945,73 @@ proptest! {
         prop_assert_eq!(&output3, &items);
     }
 }
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(64))]
+
+    #[test]
+    fn the_output_grouped_by_thread_keeps_the_order_of_each_thread(
+        per_thread in vec(vec(any::<i64>(), 1..=50), 1..=4),
+    ) {
+        let subject = OutputSubject::<(usize, i64)>::new();
+        let tracker = subject
+            .create_recording_tracker()
+            .unwrap_or_else(|err| panic!("could not create recording tracker: {err}"));
+
+        let workers = per_thread.iter().cloned().enumerate().map(|(worker, items)| {
+            let subject = subject.clone();
+            thread::spawn(move || {
+                for item in items {
+                    subject.emit((worker, item))
+                        .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+                }
+            })
+        }).collect::<Vec<_>>();
+        for worker in workers {
+            worker.join().unwrap_or_else(|_| panic!("worker thread panicked"));
+        }
+
+        let mut by_thread = tracker.output_by_thread()
+            .unwrap_or_else(|err| panic!("could not read output of tracker: {err}"))
+            .into_iter()
+            .map(ThreadOutput::into_output)
+            .collect::<Vec<_>>();
+        by_thread.sort_by_key(|output| output[0].0);
+
+        prop_assert_eq!(
+            by_thread,
+            per_thread.into_iter().enumerate().map(|(worker, items)| {
+                items.into_iter().map(|item| (worker, item)).collect::<Vec<_>>()
+            }).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn draining_an_output_tracker_while_items_are_emitted_from_another_thread_loses_no_items(
+        items in (0..=500_usize).prop_flat_map(|size| vec(any::<i64>(), size)),
+    ) {
+        let subject = OutputSubject::<i64>::new();
+        let tracker = subject
+            .create_tracker()
+            .unwrap_or_else(|err| panic!("could not create output tracker: {err}"));
+
+        let mut drained = Vec::with_capacity(items.len());
+        thread::scope(|scope| {
+            let emitter = scope.spawn(|| {
+                for item in &items {
+                    subject.emit(*item)
+                        .unwrap_or_else(|err| panic!("could not emit item {item} on output subject: {err}"));
+                }
+            });
+
+            while !emitter.is_finished() {
+                drained.extend(tracker.drain()
+                    .unwrap_or_else(|err| panic!("failed to drain tracker: {err}")));
+            }
+        });
+        drained.extend(tracker.drain()
+            .unwrap_or_else(|err| panic!("failed to drain tracker: {err}")));
+
+        prop_assert_eq!(drained, items);
+    }
+}
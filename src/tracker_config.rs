@@ -0,0 +1,57 @@
+/// Configuration of an `OutputTracker`.
+///
+/// By default, a tracker holds any number of items. To hold only a limited
+/// number of items, the `capacity` can be set along with an [`OverflowPolicy`]
+/// that determines what happens when a new item is tracked while the tracker
+/// is full.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::{OutputSubject, OverflowPolicy, TrackerConfig};
+///
+/// let subject = OutputSubject::new();
+/// let tracker = subject
+///     .create_tracker_with(TrackerConfig {
+///         capacity: 2,
+///         overflow: OverflowPolicy::DropOldest,
+///     })
+///     .unwrap();
+///
+/// for item in 1..=5 {
+///     subject.emit(item).unwrap();
+/// }
+///
+/// assert_eq!(tracker.output().unwrap(), vec![4, 5]);
+/// assert_eq!(tracker.dropped_count().unwrap(), 3);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TrackerConfig {
+    /// The maximum number of items a tracker holds.
+    ///
+    /// Defaults to `usize::MAX`, which effectively means unbounded.
+    pub capacity: usize,
+    /// What happens when an item is tracked while the tracker is full.
+    pub overflow: OverflowPolicy,
+}
+
+impl Default for TrackerConfig {
+    fn default() -> Self {
+        Self {
+            capacity: usize::MAX,
+            overflow: OverflowPolicy::default(),
+        }
+    }
+}
+
+/// Determines what happens when an item is tracked by a tracker that is full.
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowPolicy {
+    /// The oldest item is removed to make room for the new item.
+    #[default]
+    DropOldest,
+    /// The new item is discarded.
+    DropNewest,
+    /// The new item is discarded and emitting it returns an error.
+    Error,
+}
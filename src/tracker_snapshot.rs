@@ -0,0 +1,15 @@
+/// A snapshot of the state of an `OutputTracker` at one point in time.
+///
+/// A snapshot is obtained by calling the function `snapshot()` of an
+/// `OutputTracker`. With the crate feature `serde` enabled, a snapshot can be
+/// serialized and deserialized, e.g. for dumping the tracked data of a failing
+/// test into a file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TrackerSnapshot<M> {
+    /// The data collected by the tracker.
+    pub output: Vec<M>,
+    /// The number of items the tracker has discarded because its capacity was
+    /// exceeded.
+    pub dropped_count: usize,
+}
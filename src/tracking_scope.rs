@@ -0,0 +1,97 @@
+use std::cell::RefCell;
+use std::marker::PhantomData;
+use std::sync::Arc;
+
+thread_local! {
+    static CURRENT_CORRELATION_ID: RefCell<Option<Arc<str>>> = const { RefCell::new(None) };
+}
+
+/// A scope that attaches a correlation id to all data tracked while it is
+/// entered.
+///
+/// A service handling several requests concurrently can enter a scope with
+/// the id of the request it is currently handling. Every item tracked by any
+/// `OutputTracker` while the scope is entered is tagged with this id. The
+/// items belonging to one request can then be read with the function
+/// `OutputTracker::output_for()`. Recording trackers additionally store the
+/// id in each `Record`.
+///
+/// The scope is entered by calling [`TrackingScope::enter()`] and left when
+/// the returned guard is dropped. Scopes can be nested, leaving an inner scope
+/// restores the correlation id of the outer scope.
+///
+/// The correlation id is stored per thread. Data emitted on another thread is
+/// not tagged with the id unless a scope is entered on that thread too. The
+/// guard can not be sent to another thread. In async code the guard should
+/// not be held across an `.await` point, as the task may be resumed on a
+/// different thread or other tasks may run on this thread in the meantime.
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::{OutputSubject, TrackingScope};
+///
+/// let subject = OutputSubject::new();
+/// let tracker = subject.create_tracker().unwrap();
+///
+/// {
+///     let _scope = TrackingScope::enter("request-1");
+///     subject.emit("load user").unwrap();
+///     subject.emit("render page").unwrap();
+/// }
+/// {
+///     let _scope = TrackingScope::enter("request-2");
+///     subject.emit("load user").unwrap();
+/// }
+/// subject.emit("cleanup").unwrap();
+///
+/// assert_eq!(
+///     tracker.output_for("request-1").unwrap(),
+///     vec!["load user", "render page"]
+/// );
+/// assert_eq!(tracker.output_for("request-2").unwrap(), vec!["load user"]);
+/// assert_eq!(tracker.output().unwrap().len(), 4);
+/// ```
+#[derive(Debug)]
+#[must_use = "the scope is left immediately if the guard is not held"]
+pub struct TrackingScope {
+    previous: Option<Arc<str>>,
+    // the guard must be dropped on the thread it was created on
+    _not_send: PhantomData<*const ()>,
+}
+
+impl TrackingScope {
+    /// Enters a scope with the given correlation id on the current thread.
+    ///
+    /// The scope is left when the returned guard is dropped.
+    pub fn enter(correlation_id: impl Into<Arc<str>>) -> Self {
+        let previous = CURRENT_CORRELATION_ID
+            .with(|current| current.borrow_mut().replace(correlation_id.into()));
+        Self {
+            previous,
+            _not_send: PhantomData,
+        }
+    }
+
+    /// Returns the correlation id of the innermost scope entered on the
+    /// current thread, or `None` if no scope is entered.
+    ///
+    /// This can be used to enter a scope with the same id on another thread.
+    #[must_use]
+    pub fn current() -> Option<String> {
+        current_correlation_id().map(|correlation_id| correlation_id.to_string())
+    }
+}
+
+impl Drop for TrackingScope {
+    fn drop(&mut self) {
+        let previous = self.previous.take();
+        CURRENT_CORRELATION_ID.with(|current| *current.borrow_mut() = previous);
+    }
+}
+
+/// Returns the correlation id of the innermost scope entered on the current
+/// thread.
+pub fn current_correlation_id() -> Option<Arc<str>> {
+    CURRENT_CORRELATION_ID.with(|current| current.borrow().clone())
+}
@@ -0,0 +1,279 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{self, Debug, Display};
+use std::hash::Hash;
+
+/// The data collected by an `OutputTracker` compared without regard to the
+/// order in which it has been tracked.
+///
+/// When data is emitted from several threads, the order in which it is
+/// tracked is not deterministic. An [`UnorderedOutput`] compares the tracked
+/// items as a multiset, where only the number of occurrences of each item
+/// matters.
+///
+/// It is obtained by calling the function `output_unordered()` of an
+/// `OutputTracker` for items implementing `Eq + Hash`, or the function
+/// `output_unordered_by_ord()` for items implementing `Ord` but not `Hash`.
+/// It can also be constructed from any items with
+/// [`UnorderedOutput::hashed()`] or [`UnorderedOutput::ordered()`].
+///
+/// # Example
+///
+/// ```
+/// use output_tracker::non_threadsafe::OutputSubject;
+///
+/// let subject = OutputSubject::new();
+/// let tracker = subject.create_tracker().unwrap();
+///
+/// for id in [2, 3, 1] {
+///     subject.emit(id).unwrap();
+/// }
+///
+/// let output = tracker.output_unordered().unwrap();
+///
+/// output.assert_same_multiset([1, 2, 3]);
+///
+/// let difference = output.difference([1, 2, 2]);
+/// assert_eq!(difference.missing(), &[2]);
+/// assert_eq!(difference.extra(), &[3]);
+/// ```
+pub struct UnorderedOutput<M> {
+    items: Vec<M>,
+    difference: fn(&[M], Vec<M>) -> MultisetDifference<M>,
+}
+
+impl<M> Debug for UnorderedOutput<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("UnorderedOutput")
+            .field("items", &self.items)
+            .finish_non_exhaustive()
+    }
+}
+
+impl<M> Clone for UnorderedOutput<M>
+where
+    M: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            difference: self.difference,
+        }
+    }
+}
+
+impl<M> PartialEq for UnorderedOutput<M>
+where
+    M: Clone,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.difference(other.items.iter().cloned()).is_empty()
+    }
+}
+
+impl<M> UnorderedOutput<M> {
+    /// Constructs an [`UnorderedOutput`] of items that are compared using
+    /// their implementation of `Eq` and `Hash`.
+    pub fn hashed(items: impl IntoIterator<Item = M>) -> Self
+    where
+        M: Eq + Hash + Clone,
+    {
+        Self {
+            items: items.into_iter().collect(),
+            difference: hashed_difference,
+        }
+    }
+
+    /// Constructs an [`UnorderedOutput`] of items that are compared using
+    /// their implementation of `Ord`.
+    pub fn ordered(items: impl IntoIterator<Item = M>) -> Self
+    where
+        M: Ord + Clone,
+    {
+        Self {
+            items: items.into_iter().collect(),
+            difference: ordered_difference,
+        }
+    }
+
+    /// Returns the tracked items in the order they have been tracked.
+    #[must_use]
+    pub fn items(&self) -> &[M] {
+        &self.items
+    }
+
+    /// Returns the tracked items in the order they have been tracked.
+    #[must_use]
+    pub fn into_items(self) -> Vec<M> {
+        self.items
+    }
+
+    /// Returns the number of tracked items.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    /// Returns true if no items have been tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.items.is_empty()
+    }
+
+    /// Compares the tracked items with the expected items as multisets.
+    ///
+    /// The returned [`MultisetDifference`] lists the expected items that have
+    /// not been tracked and the tracked items that have not been expected.
+    pub fn difference(&self, expected: impl IntoIterator<Item = M>) -> MultisetDifference<M> {
+        (self.difference)(&self.items, expected.into_iter().collect())
+    }
+
+    /// Returns true if the tracked items and the expected items are the same
+    /// multiset, regardless of their order.
+    pub fn is_same_multiset(&self, expected: impl IntoIterator<Item = M>) -> bool {
+        self.difference(expected).is_empty()
+    }
+
+    /// Asserts that the tracked items and the expected items are the same
+    /// multiset, regardless of their order.
+    ///
+    /// # Panics
+    ///
+    /// Panics with a message listing the missing and the extra items if the
+    /// multisets are not the same.
+    #[track_caller]
+    pub fn assert_same_multiset(&self, expected: impl IntoIterator<Item = M>)
+    where
+        M: Debug,
+    {
+        let difference = self.difference(expected);
+        assert!(
+            difference.is_empty(),
+            "tracked items are not the same multiset as the expected items\n{difference}"
+        );
+    }
+}
+
+/// The difference between the tracked items and the expected items compared
+/// as multisets.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultisetDifference<M> {
+    missing: Vec<M>,
+    extra: Vec<M>,
+}
+
+impl<M> MultisetDifference<M> {
+    /// Returns the expected items that have not been tracked.
+    ///
+    /// An item that has been expected more often than it has been tracked is
+    /// listed once for each missing occurrence.
+    #[must_use]
+    pub fn missing(&self) -> &[M] {
+        &self.missing
+    }
+
+    /// Returns the tracked items that have not been expected.
+    ///
+    /// An item that has been tracked more often than it has been expected is
+    /// listed once for each extra occurrence.
+    #[must_use]
+    pub fn extra(&self) -> &[M] {
+        &self.extra
+    }
+
+    /// Returns true if no items are missing and no extra items have been
+    /// tracked.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.missing.is_empty() && self.extra.is_empty()
+    }
+}
+
+impl<M> Display for MultisetDifference<M>
+where
+    M: Debug,
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "  missing items: {:?}", self.missing)?;
+        write!(f, "    extra items: {:?}", self.extra)
+    }
+}
+
+/// Counts the occurrences of items.
+trait Counts<'a, M> {
+    fn add(&mut self, item: &'a M);
+
+    /// Removes one occurrence of the item and returns whether it was present.
+    fn remove(&mut self, item: &M) -> bool;
+}
+
+impl<'a, M> Counts<'a, M> for HashMap<&'a M, usize>
+where
+    M: Eq + Hash,
+{
+    fn add(&mut self, item: &'a M) {
+        *self.entry(item).or_default() += 1;
+    }
+
+    fn remove(&mut self, item: &M) -> bool {
+        self.get_mut(item)
+            .filter(|count| **count > 0)
+            .map(|count| *count -= 1)
+            .is_some()
+    }
+}
+
+impl<'a, M> Counts<'a, M> for BTreeMap<&'a M, usize>
+where
+    M: Ord,
+{
+    fn add(&mut self, item: &'a M) {
+        *self.entry(item).or_default() += 1;
+    }
+
+    fn remove(&mut self, item: &M) -> bool {
+        self.get_mut(item)
+            .filter(|count| **count > 0)
+            .map(|count| *count -= 1)
+            .is_some()
+    }
+}
+
+fn hashed_difference<M>(tracked: &[M], expected: Vec<M>) -> MultisetDifference<M>
+where
+    M: Eq + Hash + Clone,
+{
+    difference_with(tracked, expected, &mut HashMap::new())
+}
+
+fn ordered_difference<M>(tracked: &[M], expected: Vec<M>) -> MultisetDifference<M>
+where
+    M: Ord + Clone,
+{
+    difference_with(tracked, expected, &mut BTreeMap::new())
+}
+
+fn difference_with<'a, M>(
+    tracked: &'a [M],
+    expected: Vec<M>,
+    unmatched: &mut impl Counts<'a, M>,
+) -> MultisetDifference<M>
+where
+    M: Clone,
+{
+    for item in tracked {
+        unmatched.add(item);
+    }
+    let missing = expected
+        .into_iter()
+        .filter(|item| !unmatched.remove(item))
+        .collect();
+    let extra = tracked
+        .iter()
+        .filter(|item| unmatched.remove(item))
+        .cloned()
+        .collect();
+    MultisetDifference { missing, extra }
+}
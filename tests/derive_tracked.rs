@@ -0,0 +1,128 @@
+//! Examples for generating the tracking functions of nullable adapters using
+//! the derive macro `Tracked`.
+
+mod fixture;
+
+use asserting::prelude::*;
+use output_tracker::Tracked;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ApiMessage {
+    subject: String,
+}
+
+#[cfg(any(feature = "non-threadsafe", not(feature = "threadsafe")))]
+mod non_threadsafe_adapter {
+    use super::*;
+    use output_tracker::non_threadsafe::OutputSubject;
+
+    #[derive(Tracked)]
+    struct MailApi {
+        #[track(name = messages)]
+        message_subject: OutputSubject<ApiMessage>,
+        #[track(output = String)]
+        login_subject: OutputSubject<&'static str>,
+    }
+
+    impl MailApi {
+        fn nulled() -> Self {
+            Self {
+                message_subject: OutputSubject::new(),
+                login_subject: OutputSubject::new(),
+            }
+        }
+
+        fn login(&self, user: &'static str) {
+            _ = self.emit_login(user);
+        }
+
+        fn send(&self, message: ApiMessage) {
+            _ = self.emit_messages(message);
+        }
+    }
+
+    #[test]
+    fn tracking_functions_are_generated_for_annotated_subject_fields() {
+        let mail_api = MailApi::nulled();
+        let message_tracker = mail_api
+            .track_messages()
+            .unwrap_or_else(|err| panic!("could not create message tracker: {err}"));
+        let login_tracker = mail_api
+            .track_login()
+            .unwrap_or_else(|err| panic!("could not create login tracker: {err}"));
+
+        mail_api.login("alice");
+        mail_api.send(ApiMessage {
+            subject: "monthly report".into(),
+        });
+
+        assert_that!(login_tracker.output())
+            .ok()
+            .contains_exactly(["alice".to_string()]);
+        assert_that!(message_tracker.output())
+            .ok()
+            .contains_exactly([ApiMessage {
+                subject: "monthly report".into(),
+            }]);
+    }
+
+    #[test]
+    fn subject_accessors_are_generated_for_annotated_subject_fields() {
+        let mail_api = MailApi::nulled();
+        let message_tracker = mail_api
+            .message_subject()
+            .create_filtered_tracker(|message| message.subject.starts_with("urgent"))
+            .unwrap_or_else(|err| panic!("could not create message tracker: {err}"));
+
+        mail_api.send(ApiMessage {
+            subject: "monthly report".into(),
+        });
+        mail_api.send(ApiMessage {
+            subject: "urgent: server down".into(),
+        });
+
+        assert_that!(message_tracker.output())
+            .ok()
+            .contains_exactly([ApiMessage {
+                subject: "urgent: server down".into(),
+            }]);
+    }
+}
+
+#[cfg(feature = "threadsafe")]
+mod threadsafe_adapter {
+    use super::*;
+    use output_tracker::threadsafe::OutputSubject;
+    use std::thread;
+
+    #[derive(Tracked)]
+    #[track(variant = threadsafe)]
+    struct MailApi<M> {
+        #[track]
+        message_subject: OutputSubject<M>,
+    }
+
+    #[test]
+    fn the_threadsafe_variant_is_selected_by_an_attribute_argument() {
+        let mail_api = MailApi {
+            message_subject: OutputSubject::new(),
+        };
+        let tracker = mail_api
+            .track_message()
+            .unwrap_or_else(|err| panic!("could not create message tracker: {err}"));
+
+        thread::spawn(move || {
+            _ = mail_api.emit_message(ApiMessage {
+                subject: "sent from another thread".into(),
+            });
+        })
+        .join()
+        .unwrap_or_else(|err| panic!("could not join thread: {err:?}"));
+
+        assert_that!(tracker.output())
+            .ok()
+            .contains_exactly([ApiMessage {
+                subject: "sent from another thread".into(),
+            }]);
+    }
+}
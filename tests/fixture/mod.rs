@@ -2,8 +2,18 @@
 // Rust issue [#95513](https://github.com/rust-lang/rust/issues/95513) is fixed
 mod dummy_extern_uses {
     use asserting as _;
+    use criterion as _;
+    use futures as _;
+    #[cfg(feature = "stream")]
+    use futures_core as _;
     use output_tracker as _;
+    #[cfg(feature = "derive")]
+    use output_tracker_derive as _;
     use proptest as _;
+    #[cfg(feature = "serde")]
+    use serde as _;
+    #[cfg(feature = "serde")]
+    use serde_json as _;
     use thiserror as _;
     use version_sync as _;
 }
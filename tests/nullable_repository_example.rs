@@ -18,11 +18,12 @@ mod todo_domain {
 
 mod todo_repository {
     use crate::todo_domain::NewTodo;
+    use output_tracker::non_threadsafe::ConfigurableResponses;
     use output_tracker::non_threadsafe::Error as OtError;
     use output_tracker::non_threadsafe::OutputSubject;
     use output_tracker::non_threadsafe::OutputTracker;
 
-    #[derive(thiserror::Error, Debug)]
+    #[derive(thiserror::Error, Debug, Clone)]
     #[error("failed to access the database")]
     pub struct DbError {
         pub message: String,
@@ -49,8 +50,12 @@ mod todo_repository {
         }
 
         pub fn nulled() -> Self {
+            Self::nulled_with(ConfigurableResponses::always("NulledDb", Ok(())))
+        }
+
+        pub fn nulled_with(responses: ConfigurableResponses<Result<(), DbError>>) -> Self {
             Self {
-                db: Box::new(NulledDb),
+                db: Box::new(NulledDb { responses }),
                 todo_subject: OutputSubject::new(),
             }
         }
@@ -95,11 +100,15 @@ mod todo_repository {
         fn insert_todo(&self, todo_entity: TodoEntity) -> Result<(), DbError>;
     }
 
-    struct NulledDb;
+    struct NulledDb {
+        responses: ConfigurableResponses<Result<(), DbError>>,
+    }
 
     impl DbAccess for NulledDb {
         fn insert_todo(&self, _todo_entity: TodoEntity) -> Result<(), DbError> {
-            Ok(())
+            self.responses
+                .next()
+                .unwrap_or_else(|err| panic!("could not get response: {err}"))
         }
     }
 }
@@ -109,8 +118,9 @@ mod todo_repository {
 //
 
 use crate::todo_domain::NewTodo;
-use crate::todo_repository::{TodoEntity, TodoRepository};
+use crate::todo_repository::{DbError, TodoEntity, TodoRepository};
 use asserting::prelude::*;
+use output_tracker::non_threadsafe::ConfigurableResponses;
 
 #[test]
 fn insert_new_todo_item_into_repository() {
@@ -148,3 +158,51 @@ fn insert_new_todo_item_into_repository() {
         subject: "remember the milk".into(),
     }]);
 }
+
+#[test]
+fn failing_to_insert_a_todo_item_does_not_emit_it() {
+    //
+    // Arrange
+    //
+
+    // configure the nulled database to fail on the second insert
+    let todo_repo = TodoRepository::nulled_with(ConfigurableResponses::sequence(
+        "NulledDb",
+        [
+            Ok(()),
+            Err(DbError {
+                message: "duplicate key".into(),
+            }),
+        ],
+    ));
+
+    let todo_tracker = todo_repo
+        .track_todos()
+        .unwrap_or_else(|err| panic!("could not create todo tracker: {err}"));
+
+    //
+    // Act
+    //
+
+    let first_inserted = todo_repo.insert(NewTodo {
+        subject: "remember the milk".into(),
+    });
+    let second_inserted = todo_repo.insert(NewTodo {
+        subject: "remember the milk".into(),
+    });
+
+    //
+    // Assert
+    //
+
+    assert_that!(first_inserted).is_ok();
+    assert_that!(second_inserted).is_err();
+
+    let inserted_todos = todo_tracker
+        .output()
+        .unwrap_or_else(|err| panic!("could not read output of todo tracker: {err}"));
+
+    assert_that!(inserted_todos).contains_exactly([TodoEntity {
+        subject: "remember the milk".into(),
+    }]);
+}
@@ -0,0 +1,70 @@
+//! Example on how to verify a long sequence of tracked data against a
+//! snapshot stored in the directory `tests/snapshots` instead of writing the
+//! expected data by hand.
+//!
+//! To update the snapshot after an intended change of the emitted data, run
+//! the tests with the environment variable `OUTPUT_TRACKER_UPDATE_SNAPSHOTS=1`.
+
+mod fixture;
+
+use output_tracker::assert_output_snapshot;
+use output_tracker::non_threadsafe::{Error, OutputSubject, OutputTracker};
+
+//
+// Production code
+//
+
+#[derive(Debug, Clone, PartialEq)]
+enum OrderEvent {
+    Placed { order_id: u32, items: usize },
+    Shipped { order_id: u32 },
+    Cancelled { order_id: u32, reason: String },
+}
+
+struct OrderService {
+    event_subject: OutputSubject<OrderEvent>,
+}
+
+impl OrderService {
+    fn new() -> Self {
+        Self {
+            event_subject: OutputSubject::new(),
+        }
+    }
+
+    fn track_events(&self) -> Result<OutputTracker<OrderEvent>, Error> {
+        self.event_subject.create_tracker()
+    }
+
+    fn process_orders(&self, orders: &[(u32, usize)]) {
+        for &(order_id, items) in orders {
+            _ = self
+                .event_subject
+                .emit(OrderEvent::Placed { order_id, items });
+            if items == 0 {
+                _ = self.event_subject.emit(OrderEvent::Cancelled {
+                    order_id,
+                    reason: "empty order".into(),
+                });
+            } else {
+                _ = self.event_subject.emit(OrderEvent::Shipped { order_id });
+            }
+        }
+    }
+}
+
+//
+// Tests
+//
+
+#[test]
+fn processing_orders_emits_the_events_stored_in_the_snapshot() {
+    let order_service = OrderService::new();
+    let event_tracker = order_service
+        .track_events()
+        .unwrap_or_else(|err| panic!("could not create event tracker because {err}"));
+
+    order_service.process_orders(&[(1, 3), (2, 0), (3, 1), (4, 12), (5, 0)]);
+
+    assert_output_snapshot!(event_tracker, "processing_orders");
+}
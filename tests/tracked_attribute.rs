@@ -0,0 +1,140 @@
+//! Examples for emitting the results of adapter methods using the attribute
+//! macro `tracked`.
+#![allow(unused_crate_dependencies)]
+#![cfg(any(feature = "non-threadsafe", not(feature = "threadsafe")))]
+
+mod fixture;
+
+use asserting::prelude::*;
+use output_tracker::non_threadsafe::OutputSubject;
+use output_tracker::tracked;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct ApiMessage {
+    subject: String,
+}
+
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+#[error("failed to send mail: {0}")]
+struct ApiError(String);
+
+struct MailApi {
+    message_subject: OutputSubject<ApiMessage>,
+    receipt_subject: OutputSubject<u64>,
+    failure_subject: OutputSubject<ApiError>,
+    failed_message_subject: OutputSubject<ApiMessage>,
+    sent: u64,
+}
+
+impl MailApi {
+    fn nulled() -> Self {
+        Self {
+            message_subject: OutputSubject::new(),
+            receipt_subject: OutputSubject::new(),
+            failure_subject: OutputSubject::new(),
+            failed_message_subject: OutputSubject::new(),
+            sent: 0,
+        }
+    }
+
+    #[tracked(subject = message_subject, arg = message)]
+    #[tracked(err_subject = failure_subject)]
+    fn send(&self, message: ApiMessage) -> Result<(), ApiError> {
+        if message.subject.is_empty() {
+            return Err(ApiError("missing subject".into()));
+        }
+        Ok(())
+    }
+
+    #[tracked(subject = message_subject, err_subject = failed_message_subject, arg = message)]
+    fn send_with_receipt(&mut self, message: ApiMessage) -> Result<u64, ApiError> {
+        if message.subject.is_empty() {
+            return Err(ApiError("missing subject".into()));
+        }
+        self.sent += 1;
+        Ok(self.sent)
+    }
+
+    #[tracked(subject = receipt_subject)]
+    async fn send_async(&self, message: ApiMessage) -> Result<u64, ApiError> {
+        self.send(message).map(|()| 42)
+    }
+}
+
+fn message(subject: &str) -> ApiMessage {
+    ApiMessage {
+        subject: subject.into(),
+    }
+}
+
+#[test]
+fn the_named_argument_is_emitted_when_the_method_returns_ok() {
+    let mail_api = MailApi::nulled();
+    let tracker = mail_api
+        .message_subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create message tracker: {err}"));
+
+    _ = mail_api.send(message("first"));
+    _ = mail_api.send(message(""));
+    _ = mail_api.send(message("second"));
+
+    assert_that!(tracker.output())
+        .ok()
+        .contains_exactly([message("first"), message("second")]);
+}
+
+#[test]
+fn the_error_is_emitted_to_the_err_subject_when_the_method_returns_err() {
+    let mail_api = MailApi::nulled();
+    let tracker = mail_api
+        .failure_subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create failure tracker: {err}"));
+
+    _ = mail_api.send(message("first"));
+    _ = mail_api.send(message(""));
+
+    assert_that!(tracker.output())
+        .ok()
+        .contains_exactly([ApiError("missing subject".into())]);
+}
+
+#[test]
+fn the_named_argument_is_emitted_to_the_subject_matching_the_result() {
+    let mut mail_api = MailApi::nulled();
+    let sent_messages = mail_api
+        .message_subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create message tracker: {err}"));
+    let failed_messages = mail_api
+        .failed_message_subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create failed message tracker: {err}"));
+
+    let sent = mail_api.send_with_receipt(message("first"));
+    let failed = mail_api.send_with_receipt(message(""));
+
+    assert_that!(sent).ok().is_equal_to(1);
+    assert_that!(failed).is_err();
+    assert_that!(sent_messages.output())
+        .ok()
+        .contains_exactly([message("first")]);
+    assert_that!(failed_messages.output())
+        .ok()
+        .contains_exactly([message("")]);
+}
+
+#[test]
+fn the_returned_value_of_an_async_method_is_emitted_when_it_returns_ok() {
+    let mail_api = MailApi::nulled();
+    let tracker = mail_api
+        .receipt_subject
+        .create_tracker()
+        .unwrap_or_else(|err| panic!("could not create receipt tracker: {err}"));
+
+    let receipt = futures::executor::block_on(mail_api.send_async(message("async")));
+
+    assert_that!(receipt).ok().is_equal_to(42);
+    assert_that!(tracker.output()).ok().contains_exactly([42]);
+}